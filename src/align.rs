@@ -0,0 +1,857 @@
+//! Module for pairwise alignment with scoring matrices and gap penalties: `Global Alignment
+//! with Scoring Matrix`, `Global Alignment with Scoring Matrix and Affine Gap Penalty`,
+//! `Global Alignment with Constant Gap Penalty`, `Local Alignment with Scoring Matrix`,
+//! `Local Alignment with Affine Gap Penalty`, `Finding a Motif with Modifications`,
+//! `Overlap Alignment`
+
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+use RosalindResult;
+use RosalindError;
+use RosalindError::{InvalidInputParameters, UnknownScoringSymbol, ScoringMatrixParseError};
+use constants::GAP_SYMBOL;
+use fasta::{FastaReader, FastaRecord};
+
+/// BLOSUM62 scoring matrix in NCBI format
+pub const BLOSUM62: &str = "#  Matrix made by matblas from blosum62.iij
+#  * column uses minimum score
+#  BLOSUM Clustered Scoring Matrix in 1/2 Bit Units
+#  Blocks Database = /data/blocks_5.0/blocks.dat
+#  Cluster Percentage: >= 62
+#  Entropy =   0.6979, Expected =  -0.5209
+   A  R  N  D  C  Q  E  G  H  I  L  K  M  F  P  S  T  W  Y  V  B  Z  X  *
+A  4 -1 -2 -2  0 -1 -1  0 -2 -1 -1 -1 -1 -2 -1  1  0 -3 -2  0 -2 -1  0 -4
+R -1  5  0 -2 -3  1  0 -2  0 -3 -2  2 -1 -3 -2 -1 -1 -3 -2 -3 -1  0 -1 -4
+N -2  0  6  1 -3  0  0  0  1 -3 -3  0 -2 -3 -2  1  0 -4 -2 -3  3  0 -1 -4
+D -2 -2  1  6 -3  0  2 -1 -1 -3 -4 -1 -3 -3 -1  0 -1 -4 -3 -3  4  1 -1 -4
+C  0 -3 -3 -3  9 -3 -4 -3 -3 -1 -1 -3 -1 -2 -3 -1 -1 -2 -2 -1 -3 -3 -2 -4
+Q -1  1  0  0 -3  5  2 -2  0 -3 -2  1  0 -3 -1  0 -1 -2 -1 -2  0  3 -1 -4
+E -1  0  0  2 -4  2  5 -2  0 -3 -3  1 -2 -3 -1  0 -1 -3 -2 -2  1  4 -1 -4
+G  0 -2  0 -1 -3 -2 -2  6 -2 -4 -4 -2 -3 -3 -2  0 -2 -2 -3 -3 -1 -2 -1 -4
+H -2  0  1 -1 -3  0  0 -2  8 -3 -3 -1 -2 -1 -2 -1 -2 -2  2 -3  0  0 -1 -4
+I -1 -3 -3 -3 -1 -3 -3 -4 -3  4  2 -3  1  0 -3 -2 -1 -3 -1  3 -3 -3 -1 -4
+L -1 -2 -3 -4 -1 -2 -3 -4 -3  2  4 -2  2  0 -3 -2 -1 -2 -1  1 -4 -3 -1 -4
+K -1  2  0 -1 -3  1  1 -2 -1 -3 -2  5 -1 -3 -1  0 -1 -3 -2 -2  0  1 -1 -4
+M -1 -1 -2 -3 -1  0 -2 -3 -2  1  2 -1  5  0 -2 -1 -1 -1 -1  1 -3 -1 -1 -4
+F -2 -3 -3 -3 -2 -3 -3 -3 -1  0  0 -3  0  6 -4 -2 -2  1  3 -1 -3 -3 -1 -4
+P -1 -2 -2 -1 -3 -1 -1 -2 -2 -3 -3 -1 -2 -4  7 -1 -1 -4 -3 -2 -2 -1 -2 -4
+S  1 -1  1  0 -1  0  0  0 -1 -2 -2  0 -1 -2 -1  4  1 -3 -2 -2  0  0  0 -4
+T  0 -1  0 -1 -1 -1 -1 -2 -2 -1 -1 -1 -1 -2 -1  1  5 -2 -2  0 -1 -1  0 -4
+W -3 -3 -4 -4 -2 -2 -3 -2 -2 -3 -2 -3 -1  1 -4 -3 -2 11  2 -3 -4 -3 -2 -4
+Y -2 -2 -2 -3 -2 -1 -2 -3  2 -1 -1 -2 -1  3 -3 -2 -2  2  7 -1 -3 -2 -1 -4
+V  0 -3 -3 -3 -1 -2 -2 -3 -3  3  1 -2  1 -1 -2 -2  0 -3 -1  4 -3 -2 -1 -4
+B -2 -1  3  4 -3  0  1 -1  0 -3 -4  0 -3 -3 -2  0 -1 -4 -3 -3  4  1 -1 -4
+Z -1  0  0  1 -3  3  4 -2  0 -3 -3  1 -1 -3 -1  0 -1 -3 -2 -2  1  4 -1 -4
+X  0 -1 -1 -1 -2 -1 -1 -1 -1 -1 -1 -1 -1 -1 -2  0  0 -2 -1 -1 -1 -1 -1 -4
+* -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4  1
+";
+
+/// PAM250 scoring matrix in NCBI format
+pub const PAM250: &str = "   A  C  D  E  F  G  H  I  K  L  M  N  P  Q  R  S  T  V  W  Y
+A  2 -2  0  0 -3  1 -1 -1 -1 -2 -1  0  1  0 -2  1  1  0 -6 -3
+C -2 12 -5 -5 -4 -3 -3 -2 -5 -6 -5 -4 -3 -5 -4  0 -2 -2 -8  0
+D  0 -5  4  3 -6  1  1 -2  0 -4 -3  2 -1  2 -1  0  0 -2 -7 -4
+E  0 -5  3  4 -5  0  1 -2  0 -3 -2  1 -1  2 -1  0  0 -2 -7 -4
+F -3 -4 -6 -5  9 -5 -2  1 -5  2  0 -3 -5 -5 -4 -3 -3 -1  0  7
+G  1 -3  1  0 -5  5 -2 -3 -2 -4 -3  0  0 -1 -3  1  0 -1 -7 -5
+H -1 -3  1  1 -2 -2  6 -2  0 -2 -2  2  0  3  2 -1 -1 -2 -3  0
+I -1 -2 -2 -2  1 -3 -2  5 -2  2  2 -2 -2 -2 -2 -1  0  4 -5 -1
+K -1 -5  0  0 -5 -2  0 -2  5 -3  0  1 -1  1  3  0  0 -2 -3 -4
+L -2 -6 -4 -3  2 -4 -2  2 -3  6  4 -3 -3 -2 -3 -3 -2  2 -2 -1
+M -1 -5 -3 -2  0 -3 -2  2  0  4  6 -2 -2 -1  0 -2 -1  2 -4 -2
+N  0 -4  2  1 -3  0  2 -2  1 -3 -2  2  0  1  0  1  0 -2 -4 -2
+P  1 -3 -1 -1 -5  0  0 -2 -1 -3 -2  0  6  0  0  1  0 -1 -6 -5
+Q  0 -5  2  2 -5 -1  3 -2  1 -2 -1  1  0  4  1 -1 -1 -2 -5 -4
+R -2 -4 -1 -1 -4 -3  2 -2  3 -3  0  0  0  1  6  0 -1 -2  2 -4
+S  1  0  0  0 -3  1 -1 -1  0 -3 -2  1  1 -1  0  2  1 -1 -2 -3
+T  1 -2  0  0 -3  0 -1  0  0 -2 -1  0  0 -1 -1  1  3  0 -5 -3
+V  0 -2 -2 -2 -1 -1 -2  4 -2  2  2 -2 -1 -2 -2 -1  0  4 -6 -2
+W -6 -8 -7 -7  0 -7 -3 -5 -3 -2 -4 -4 -6 -5  2 -2 -5 -6 17  0
+Y -3  0 -4 -4  7 -5  0 -1 -4 -1 -2 -2 -5 -4 -4 -3 -3 -2  0 10
+";
+
+// scoring matrices cover ASCII symbols
+const SYMBOLS: usize = 128;
+
+/// This structure contains scores of aligned pairs of symbols, symbols are case-insensitive
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScoringMatrix {
+  known: Vec<bool>,
+  scores: Vec<i32>,
+}
+
+impl ScoringMatrix {
+  /// This function returns BLOSUM62 scoring matrix
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::align::*;
+  ///
+  /// let blosum62 = ScoringMatrix::blosum62();
+  /// assert_eq!(blosum62.score('W', 'W').unwrap(), 11);
+  /// assert_eq!(blosum62.score('a', 'R').unwrap(), -1);
+  /// ```
+  pub fn blosum62() -> ScoringMatrix {
+    BLOSUM62.parse().unwrap()
+  }
+
+  /// This function returns PAM250 scoring matrix
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::align::*;
+  ///
+  /// assert_eq!(ScoringMatrix::pam250().score('W', 'W').unwrap(), 17);
+  /// ```
+  pub fn pam250() -> ScoringMatrix {
+    PAM250.parse().unwrap()
+  }
+
+  /// This function returns scoring matrix which scores any pair of equal ASCII symbols
+  /// by `match_score` and any pair of different ones by `mismatch_score`
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::align::*;
+  ///
+  /// let matrix = ScoringMatrix::match_mismatch(1, -1);
+  /// assert_eq!(matrix.score('A', 'a').unwrap(), 1);
+  /// assert_eq!(matrix.score('A', 'C').unwrap(), -1);
+  /// ```
+  pub fn match_mismatch(match_score: i32, mismatch_score: i32) -> ScoringMatrix {
+    let mut scores = vec![mismatch_score; SYMBOLS * SYMBOLS];
+    for a in 0..SYMBOLS {
+      for b in 0..SYMBOLS {
+        if (a as u8).eq_ignore_ascii_case(&(b as u8)) { scores[a * SYMBOLS + b] = match_score; }
+      }
+    }
+    ScoringMatrix {known: vec![true; SYMBOLS], scores}
+  }
+
+  /// This function returns score of aligned symbols `a` and `b`
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::RosalindError::UnknownScoringSymbol;
+  /// use rosalind::align::*;
+  ///
+  /// assert_eq!(ScoringMatrix::pam250().score('J', 'A').unwrap_err(), UnknownScoringSymbol('J'));
+  /// ```
+  pub fn score(&self, a: char, b: char) -> RosalindResult<i32> {
+    let (a, b) = (try!(self.index(a)), try!(self.index(b)));
+    Ok(self.scores[a * SYMBOLS + b])
+  }
+
+  fn index(&self, symbol: char) -> RosalindResult<usize> {
+    if symbol.is_ascii() && self.known[symbol as usize] { Ok(symbol as usize) } else { Err(UnknownScoringSymbol(symbol)) }
+  }
+
+  // gap symbol is never accepted in aligned strings, so gaps of alignment are unambiguous
+  fn validate(&self, s: &str) -> RosalindResult<()> {
+    for symbol in s.chars() {
+      if symbol == GAP_SYMBOL { return Err(UnknownScoringSymbol(symbol)); }
+      try!(self.index(symbol));
+    }
+    Ok(())
+  }
+
+  // scores pair of symbols which are already validated
+  fn score_bytes(&self, a: u8, b: u8) -> i32 {
+    self.scores[a as usize * SYMBOLS + b as usize]
+  }
+}
+
+// parses symbol of scoring matrix header or row
+fn parse_symbol(field: &str, line: usize) -> RosalindResult<u8> {
+  match field.as_bytes() {
+    &[symbol] if symbol.is_ascii_graphic() => Ok(symbol),
+    _ => Err(ScoringMatrixParseError(line)),
+  }
+}
+
+impl FromStr for ScoringMatrix {
+  type Err = RosalindError;
+
+  /// This function parses scoring matrix in NCBI format: lines starting with `#` are
+  /// comments, the first line contains symbols of columns and the other lines contain
+  /// symbol of row followed by scores
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::RosalindError::ScoringMatrixParseError;
+  /// use rosalind::align::*;
+  ///
+  /// let matrix: ScoringMatrix = "# DNA\n   A  C\nA  2 -1\nC -1  2\n".parse().unwrap();
+  /// assert_eq!(matrix.score('c', 'a').unwrap(), -1);
+  /// assert_eq!("   A  C\nA  2 -1\nC -1\n".parse::<ScoringMatrix>().unwrap_err(), ScoringMatrixParseError(3));
+  /// ```
+  fn from_str(matrix: &str) -> RosalindResult<ScoringMatrix> {
+    let mut lines = matrix
+      .lines()
+      .enumerate()
+      .map(|(i, line)| (i + 1, line.trim()))
+      .filter(|&(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+    let (header_line, header) = match lines.next() {
+      Some(line) => line,
+      None => return Err(ScoringMatrixParseError(1)),
+    };
+    let columns: Vec<u8> = try!(header.split_whitespace().map(|field| parse_symbol(field, header_line)).collect());
+
+    let mut known = vec![false; SYMBOLS];
+    let mut scores = vec![0; SYMBOLS * SYMBOLS];
+    let mut rows = 0;
+    for (line_number, line) in lines {
+      let mut fields = line.split_whitespace();
+      let row = try!(parse_symbol(fields.next().unwrap(), line_number));
+      let values: Vec<i32> = try!(fields.map(|field| field.parse().map_err(|_| ScoringMatrixParseError(line_number))).collect());
+      if values.len() != columns.len() || !columns.contains(&row) || known[row as usize] {
+        return Err(ScoringMatrixParseError(line_number));
+      }
+
+      for (&column, &value) in columns.iter().zip(values.iter()) {
+        for &a in [row.to_ascii_uppercase(), row.to_ascii_lowercase()].iter() {
+          for &b in [column.to_ascii_uppercase(), column.to_ascii_lowercase()].iter() {
+            scores[a as usize * SYMBOLS + b as usize] = value;
+          }
+        }
+      }
+      known[row.to_ascii_uppercase() as usize] = true;
+      known[row.to_ascii_lowercase() as usize] = true;
+      rows += 1;
+    }
+
+    if rows != columns.len() { return Err(ScoringMatrixParseError(matrix.lines().count())); }
+    Ok(ScoringMatrix {known, scores})
+  }
+}
+
+/// This enumeration contains penalties of gaps: gap of length `l` costs `l * penalty`
+/// with `Linear` penalty and `open + (l - 1) * extend` with `Affine` penalty,
+/// e.g. constant gap penalty is affine penalty without extension cost. Extension
+/// of affine gap must not cost more than its opening.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GapPenalty {
+  Linear(i32),
+  Affine {open: i32, extend: i32},
+}
+
+impl GapPenalty {
+  // returns costs of opening and extending gap
+  fn costs(&self) -> (i32, i32) {
+    match *self {
+      GapPenalty::Linear(penalty) => (penalty, penalty),
+      GapPenalty::Affine {open, extend} => (open, extend),
+    }
+  }
+}
+
+/// This structure contains score of alignment, aligned strings and zero-based ranges
+/// of aligned substrings of `s` and `t`, gaps in aligned strings are denoted by `-`
+#[derive(PartialEq, Debug)]
+pub struct Alignment {
+  pub score: i32,
+  pub s_aligned: String,
+  pub t_aligned: String,
+  pub s_range: Range<usize>,
+  pub t_range: Range<usize>,
+}
+
+impl fmt::Display for Alignment {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}\n{}\n{}", self.score, self.s_aligned, self.t_aligned)
+  }
+}
+
+const NEG_INFINITY: i32 = i32::MIN / 2;
+
+// traceback of cell keeps source of the best score in the lowest bits
+// and whether gaps ending in the cell extend previous gaps
+const FROM_DIAGONAL: u8 = 0;
+const FROM_UP: u8 = 1;
+const FROM_LEFT: u8 = 2;
+const FROM_START: u8 = 3;
+const SOURCE_MASK: u8 = 3;
+const UP_EXTENDS: u8 = 4;
+const LEFT_EXTENDS: u8 = 8;
+
+// traceback of alignment packed into half a byte per cell
+struct Traceback {
+  width: usize,
+  cells: Vec<u8>,
+}
+
+impl Traceback {
+  fn new(height: usize, width: usize) -> Traceback {
+    Traceback {width, cells: vec![0; (height * width + 1) / 2]}
+  }
+
+  fn get(&self, i: usize, j: usize) -> u8 {
+    let k = i * self.width + j;
+    (self.cells[k / 2] >> (k % 2 * 4)) & 0xF
+  }
+
+  fn set(&mut self, i: usize, j: usize, cell: u8) {
+    let k = i * self.width + j;
+    self.cells[k / 2] = (self.cells[k / 2] & !(0xF << (k % 2 * 4))) | (cell << (k % 2 * 4));
+  }
+}
+
+// states of traceback: symbols are aligned, symbol of `s` is aligned to gap
+// or symbol of `t` is aligned to gap
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+  Best,
+  Up,
+  Left,
+}
+
+/// This enumeration contains modes of alignment of strings `s` and `t`: `Global` aligns
+/// whole strings, `Local` aligns substrings of `s` and `t`, `Fitting` aligns whole `t`
+/// against substring of `s` and `Overlap` aligns suffix of `s` against prefix of `t`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AlignmentMode {
+  Global,
+  Local,
+  Fitting,
+  Overlap,
+}
+
+// dynamic programming with Gotoh's affine gaps, global alignment is found by Needleman-Wunsch
+// algorithm and local one by Smith-Waterman algorithm, scores are kept in rows
+fn align_with(s: &[u8], t: &[u8], matrix: &ScoringMatrix, gap: GapPenalty, mode: AlignmentMode) -> Alignment {
+  let (open, extend) = gap.costs();
+  let gap_cost = |len: usize| open + (len as i32 - 1) * extend;
+  // prefix of `s` is skipped for free in all modes but global, prefix of `t` in local mode only
+  let (free_s_prefix, free_t_prefix) = (mode != AlignmentMode::Global, mode == AlignmentMode::Local);
+  let local = mode == AlignmentMode::Local;
+  let width = t.len() + 1;
+  let mut traceback = Traceback::new(s.len() + 1, width);
+
+  // best scores and scores of alignments ending with gap in `t`
+  let mut best = vec![0; width];
+  let mut up = vec![NEG_INFINITY; width];
+  traceback.set(0, 0, FROM_START);
+  for (j, score) in best.iter_mut().enumerate().skip(1) {
+    if !free_t_prefix { *score = -gap_cost(j); }
+    traceback.set(0, j, if free_t_prefix { FROM_START } else { FROM_LEFT | if j > 1 { LEFT_EXTENDS } else { 0 } });
+  }
+
+  let (mut best_score, mut best_end) = match mode {
+    AlignmentMode::Fitting => (best[t.len()], (0, t.len())),
+    _ => (0, (0, 0)),
+  };
+  for i in 1..(s.len() + 1) {
+    let mut diagonal = best[0];
+    best[0] = if free_s_prefix { 0 } else { -gap_cost(i) };
+    up[0] = if free_s_prefix { NEG_INFINITY } else { best[0] };
+    traceback.set(i, 0, if free_s_prefix { FROM_START } else { FROM_UP | if i > 1 { UP_EXTENDS } else { 0 } });
+
+    // score of alignment ending with gap in `s`
+    let mut left = NEG_INFINITY;
+    for j in 1..width {
+      let mut cell = FROM_DIAGONAL;
+
+      let (up_open, up_extend) = (best[j] - open, up[j] - extend);
+      up[j] = if up_extend > up_open { cell |= UP_EXTENDS; up_extend } else { up_open };
+      let (left_open, left_extend) = (best[j - 1] - open, left - extend);
+      left = if left_extend > left_open { cell |= LEFT_EXTENDS; left_extend } else { left_open };
+
+      let mut score = diagonal + matrix.score_bytes(s[i - 1], t[j - 1]);
+      if up[j] > score { score = up[j]; cell |= FROM_UP; }
+      if left > score { score = left; cell = (cell & !SOURCE_MASK) | FROM_LEFT; }
+      if local && score <= 0 { score = 0; cell |= FROM_START; }
+
+      // local alignment ends anywhere, fitting alignment ends at the end of `t`
+      let is_end = local || mode == AlignmentMode::Fitting && j == t.len();
+      if is_end && score > best_score { best_score = score; best_end = (i, j); }
+
+      diagonal = best[j];
+      best[j] = score;
+      traceback.set(i, j, cell);
+    }
+  }
+
+  match mode {
+    AlignmentMode::Global => { best_score = best[t.len()]; best_end = (s.len(), t.len()); },
+    // overlap alignment ends at the end of `s`, longer overlaps are preferred
+    AlignmentMode::Overlap => {
+      best_score = best[0];
+      best_end = (s.len(), 0);
+      for (j, &score) in best.iter().enumerate().skip(1) {
+        if score >= best_score { best_score = score; best_end = (s.len(), j); }
+      }
+    },
+    _ => {},
+  }
+
+  let (mut s_aligned, mut t_aligned) = (Vec::new(), Vec::new());
+  let (mut i, mut j) = best_end;
+  let mut state = State::Best;
+  loop {
+    let cell = traceback.get(i, j);
+    match state {
+      State::Best => match cell & SOURCE_MASK {
+        FROM_START => break,
+        FROM_UP => state = State::Up,
+        FROM_LEFT => state = State::Left,
+        _ => {
+          i -= 1;
+          j -= 1;
+          s_aligned.push(s[i] as char);
+          t_aligned.push(t[j] as char);
+        },
+      },
+      State::Up => {
+        i -= 1;
+        s_aligned.push(s[i] as char);
+        t_aligned.push(GAP_SYMBOL);
+        if cell & UP_EXTENDS == 0 { state = State::Best; }
+      },
+      State::Left => {
+        j -= 1;
+        s_aligned.push(GAP_SYMBOL);
+        t_aligned.push(t[j] as char);
+        if cell & LEFT_EXTENDS == 0 { state = State::Best; }
+      },
+    }
+  }
+
+  Alignment {
+    score: best_score,
+    s_aligned: s_aligned.into_iter().rev().collect(),
+    t_aligned: t_aligned.into_iter().rev().collect(),
+    s_range: i..best_end.0,
+    t_range: j..best_end.1,
+  }
+}
+
+/// This function finds optimal global alignment of strings `s` and `t` (Needleman-Wunsch
+/// algorithm) scoring aligned symbols by `matrix` and penalizing gaps by `gap`. Strings
+/// must not contain gap symbol `-` and affine gap extension more costly than opening
+/// is an error.
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::{InvalidInputParameters, UnknownScoringSymbol};
+/// use rosalind::align::*;
+///
+/// let blosum62 = ScoringMatrix::blosum62();
+/// let alignment = global_alignment("PLEASANTLY", "MEANLY", &blosum62, GapPenalty::Linear(5)).unwrap();
+/// assert_eq!(alignment.score, 8);
+///
+/// let alignment = global_alignment("PRTEINS", "PRTWPSEIN", &blosum62, GapPenalty::Affine {open: 11, extend: 1}).unwrap();
+/// assert_eq!(alignment.to_string(), "8\nPRT---EINS\nPRTWPSEIN-");
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -1);
+/// assert_eq!(global_alignment("GATTACA", "GCATGCT", &dna, GapPenalty::Linear(1)).unwrap().score, 0);
+/// assert_eq!(global_alignment("GATTACA", "J", &blosum62, GapPenalty::Linear(1)).unwrap_err(), UnknownScoringSymbol('J'));
+/// assert_eq!(global_alignment("A-C", "AC", &dna, GapPenalty::Linear(1)).unwrap_err(), UnknownScoringSymbol('-'));
+///
+/// let gap = GapPenalty::Affine {open: 1, extend: 5};
+/// assert_eq!(global_alignment("AAAAAA", "AA", &dna, gap).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn global_alignment(s: &str, t: &str, matrix: &ScoringMatrix, gap: GapPenalty) -> RosalindResult<Alignment> {
+  align(s, t, matrix, gap, AlignmentMode::Global)
+}
+
+/// This function finds optimal global alignment of two strings in FASTA dataset
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::*;
+///
+/// let dataset = ">Rosalind_67
+///   PLEASANTLY
+///   >Rosalind_17
+///   MEANLY";
+///
+/// let blosum62 = ScoringMatrix::blosum62();
+/// let constant_gap = GapPenalty::Affine {open: 5, extend: 0};
+/// assert_eq!(global_alignment_in_dataset(dataset, &blosum62, constant_gap).unwrap().score, 13);
+/// ```
+pub fn global_alignment_in_dataset(dataset: &str, matrix: &ScoringMatrix, gap: GapPenalty) -> RosalindResult<Alignment> {
+  global_alignment_in_records(FastaReader::new(dataset.as_bytes()), matrix, gap)
+}
+
+/// This function finds optimal global alignment of sequences of the only two given FASTA
+/// records
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::align::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -1);
+/// let records = vec![Ok(FastaRecord::new("s", "", "GATTACA")), Ok(FastaRecord::new("t", "", "GATACA"))];
+/// assert_eq!(global_alignment_in_records(records, &dna, GapPenalty::Linear(2)).unwrap().score, 4);
+/// assert_eq!(global_alignment_in_records(vec![], &dna, GapPenalty::Linear(2)).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn global_alignment_in_records<I>(records: I, matrix: &ScoringMatrix, gap: GapPenalty) -> RosalindResult<Alignment>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  align_in_records(records, matrix, gap, AlignmentMode::Global)
+}
+
+/// This function finds optimal local alignment of strings `s` and `t` (Smith-Waterman
+/// algorithm) scoring aligned symbols by `matrix` and penalizing gaps by `gap`.
+/// Aligned substrings are `s[s_range]` and `t[t_range]`, empty alignment with zero score
+/// is returned when no pair of substrings has positive score. If there are several
+/// optimal alignments, any of them is returned. Scores are kept in rows
+/// and traceback takes half a byte per pair of symbols, so 10 kbp strings are aligned
+/// in 50 MB of memory.
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::*;
+///
+/// let (s, t) = ("MEANLYPRTEINSTRING", "PLEASANTLYEINSTEIN");
+/// let alignment = local_alignment(s, t, &ScoringMatrix::pam250(), GapPenalty::Linear(5)).unwrap();
+/// assert_eq!(alignment.score, 23);
+/// assert_eq!(&s[alignment.s_range], "MEANLYPRTEINSTRIN");
+/// assert_eq!(&t[alignment.t_range], "LEASANTLYEINSTEIN");
+/// ```
+pub fn local_alignment(s: &str, t: &str, matrix: &ScoringMatrix, gap: GapPenalty) -> RosalindResult<Alignment> {
+  align(s, t, matrix, gap, AlignmentMode::Local)
+}
+
+/// This function finds optimal local alignment of two strings in FASTA dataset
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::*;
+///
+/// let dataset = ">Rosalind_8
+///   PLEASANTLY
+///   >Rosalind_18
+///   MEANLY";
+///
+/// let gap = GapPenalty::Affine {open: 11, extend: 1};
+/// let alignment = local_alignment_in_dataset(dataset, &ScoringMatrix::blosum62(), gap).unwrap();
+/// assert_eq!(alignment.to_string(), "12\nLEAS\nMEAN");
+/// ```
+pub fn local_alignment_in_dataset(dataset: &str, matrix: &ScoringMatrix, gap: GapPenalty) -> RosalindResult<Alignment> {
+  local_alignment_in_records(FastaReader::new(dataset.as_bytes()), matrix, gap)
+}
+
+/// This function finds optimal local alignment of sequences of the only two given FASTA
+/// records, a different number of records gives `InvalidInputParameters`
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::align::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -1);
+/// let records = vec![Ok(FastaRecord::new("s", "", "CCCGATTACA")), Ok(FastaRecord::new("t", "", "GATTACAGGG"))];
+/// assert_eq!(local_alignment_in_records(records, &dna, GapPenalty::Linear(2)).unwrap().t_range, 0..7);
+/// assert_eq!(local_alignment_in_records(vec![], &dna, GapPenalty::Linear(2)).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn local_alignment_in_records<I>(records: I, matrix: &ScoringMatrix, gap: GapPenalty) -> RosalindResult<Alignment>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  align_in_records(records, matrix, gap, AlignmentMode::Local)
+}
+
+/// This function finds optimal alignment of strings `s` and `t` in alignment `mode`
+/// scoring aligned symbols by `matrix` and penalizing gaps by `gap`. Fitting alignment
+/// finds approximate occurrence of motif `t` in `s` and overlap alignment finds
+/// approximate overlap of reads `s` and `t`, aligned substrings are `s[s_range]`
+/// and `t[t_range]`.
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::*;
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -1);
+/// let fitting = align("CCCGATTACAGGG", "GATTCA", &dna, GapPenalty::Linear(1), AlignmentMode::Fitting).unwrap();
+/// assert_eq!(fitting.score, 5);
+/// assert_eq!(fitting.t_range, 0..6);
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -2);
+/// let overlap = align("GGGGATTACA", "ATTACACCC", &dna, GapPenalty::Linear(2), AlignmentMode::Overlap).unwrap();
+/// assert_eq!(overlap.to_string(), "6\nATTACA\nATTACA");
+/// assert_eq!((overlap.s_range, overlap.t_range), (4..10, 0..6));
+/// ```
+pub fn align(s: &str, t: &str, matrix: &ScoringMatrix, gap: GapPenalty, mode: AlignmentMode) -> RosalindResult<Alignment> {
+  let (open, extend) = gap.costs();
+  if extend > open { return Err(InvalidInputParameters); }
+  try!(matrix.validate(s));
+  try!(matrix.validate(t));
+  Ok(align_with(s.as_bytes(), t.as_bytes(), matrix, gap, mode))
+}
+
+/// This function finds optimal alignment of two strings in FASTA dataset in alignment `mode`
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::*;
+///
+/// let dataset = ">Rosalind_54
+///   CTAAGGGATTCCGGTAATTAGACAG
+///   >Rosalind_45
+///   ATAGACCATATGTCAGTGACTGTGTAA";
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -2);
+/// let alignment = align_in_dataset(dataset, &dna, GapPenalty::Linear(2), AlignmentMode::Overlap).unwrap();
+/// assert_eq!(alignment.score, 1);
+/// ```
+pub fn align_in_dataset(dataset: &str, matrix: &ScoringMatrix, gap: GapPenalty, mode: AlignmentMode) -> RosalindResult<Alignment> {
+  align_in_records(FastaReader::new(dataset.as_bytes()), matrix, gap, mode)
+}
+
+/// This function aligns sequences of a pair of FASTA records in alignment `mode`,
+/// record count other than two is rejected
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::align::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -1);
+/// let records = vec![Ok(FastaRecord::new("s", "", "CCCGATTACAGGG")), Ok(FastaRecord::new("t", "", "GATTACA"))];
+/// let alignment = align_in_records(records, &dna, GapPenalty::Linear(1), AlignmentMode::Fitting).unwrap();
+/// assert_eq!(alignment.s_range, 3..10);
+/// assert_eq!(align_in_records(vec![], &dna, GapPenalty::Linear(1), AlignmentMode::Fitting).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn align_in_records<I>(records: I, matrix: &ScoringMatrix, gap: GapPenalty, mode: AlignmentMode) -> RosalindResult<Alignment>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+  if records.len() != 2 { return Err(InvalidInputParameters); }
+  align(&records[0].seq, &records[1].seq, matrix, gap, mode)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::{Traceback, FROM_UP, FROM_LEFT, FROM_START, UP_EXTENDS, LEFT_EXTENDS};
+  use super::super::RosalindError::{InvalidInputParameters, UnknownScoringSymbol, ScoringMatrixParseError};
+  use utils::random_string;
+
+  const PROTEIN: &'static str = "ACDEFGHIKLMNPQRSTVWY";
+
+  // scores aligned strings, gaps are penalized by their length
+  fn alignment_score(alignment: &Alignment, matrix: &ScoringMatrix, gap: GapPenalty) -> i32 {
+    let (open, extend) = gap.costs();
+    let pairs: Vec<(char, char)> = alignment.s_aligned.chars().zip(alignment.t_aligned.chars()).collect();
+    let mut score = 0;
+    for (k, &(a, b)) in pairs.iter().enumerate() {
+      if a == '-' {
+        score -= if k > 0 && pairs[k - 1].0 == '-' { extend } else { open };
+      } else if b == '-' {
+        score -= if k > 0 && pairs[k - 1].1 == '-' { extend } else { open };
+      } else {
+        score += matrix.score(a, b).unwrap();
+      }
+    }
+    score
+  }
+
+  fn assert_alignment(s: &str, t: &str, alignment: &Alignment, matrix: &ScoringMatrix, gap: GapPenalty) {
+    assert_eq!(alignment.s_aligned.len(), alignment.t_aligned.len());
+    assert_eq!(alignment.s_aligned.replace("-", ""), &s[alignment.s_range.clone()]);
+    assert_eq!(alignment.t_aligned.replace("-", ""), &t[alignment.t_range.clone()]);
+    assert_eq!(alignment_score(alignment, matrix, gap), alignment.score);
+  }
+
+  #[test]
+  fn it_should_parse_symmetric_scoring_matrices() {
+    for matrix in [ScoringMatrix::blosum62(), ScoringMatrix::pam250()].iter() {
+      for a in "ACDEFGHIKLMNPQRSTVWY".chars() {
+        for b in "ACDEFGHIKLMNPQRSTVWY".chars() {
+          assert_eq!(matrix.score(a, b).unwrap(), matrix.score(b, a).unwrap());
+        }
+      }
+    }
+    assert_eq!(ScoringMatrix::blosum62().score('*', '*').unwrap(), 1);
+    assert_eq!(ScoringMatrix::blosum62().score('B', 'D').unwrap(), 4);
+    assert_eq!(ScoringMatrix::pam250().score('C', 'C').unwrap(), 12);
+    assert_eq!(ScoringMatrix::pam250().score('B', 'D').unwrap_err(), UnknownScoringSymbol('B'));
+  }
+
+  #[test]
+  fn it_should_return_error_when_scoring_matrix_is_malformed() {
+    assert_eq!("".parse::<ScoringMatrix>().unwrap_err(), ScoringMatrixParseError(1));
+    assert_eq!("# A\n   A  CC\nA 1 0".parse::<ScoringMatrix>().unwrap_err(), ScoringMatrixParseError(2));
+    assert_eq!("   A  C\nA  1  x\nC  0  1".parse::<ScoringMatrix>().unwrap_err(), ScoringMatrixParseError(2));
+    assert_eq!("   A  C\nA  1  0\nA  0  1".parse::<ScoringMatrix>().unwrap_err(), ScoringMatrixParseError(3));
+    assert_eq!("   A  C\nA  1  0\nG  0  1".parse::<ScoringMatrix>().unwrap_err(), ScoringMatrixParseError(3));
+    assert_eq!("   A  C\nA  1  0\n".parse::<ScoringMatrix>().unwrap_err(), ScoringMatrixParseError(2));
+  }
+
+  #[test]
+  fn it_should_align_globally_with_linear_gap_penalty() {
+    let blosum62 = ScoringMatrix::blosum62();
+    let alignment = global_alignment("PLEASANTLY", "MEANLY", &blosum62, GapPenalty::Linear(5)).unwrap();
+    assert_eq!(alignment.score, 8);
+    assert_eq!((alignment.s_range.clone(), alignment.t_range.clone()), (0..10, 0..6));
+    assert_alignment("PLEASANTLY", "MEANLY", &alignment, &blosum62, GapPenalty::Linear(5));
+  }
+
+  #[test]
+  fn it_should_align_globally_with_affine_gap_penalty() {
+    let blosum62 = ScoringMatrix::blosum62();
+    let gap = GapPenalty::Affine {open: 11, extend: 1};
+    let alignment = global_alignment("PRTEINS", "PRTWPSEIN", &blosum62, gap).unwrap();
+    assert_eq!(alignment.score, 8);
+    assert_alignment("PRTEINS", "PRTWPSEIN", &alignment, &blosum62, gap);
+  }
+
+  #[test]
+  fn it_should_align_globally_with_constant_gap_penalty() {
+    let blosum62 = ScoringMatrix::blosum62();
+    let gap = GapPenalty::Affine {open: 5, extend: 0};
+    let alignment = global_alignment("PLEASANTLY", "MEANLY", &blosum62, gap).unwrap();
+    assert_eq!(alignment.score, 13);
+    assert_alignment("PLEASANTLY", "MEANLY", &alignment, &blosum62, gap);
+  }
+
+  #[test]
+  fn it_should_align_empty_strings() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let gap = GapPenalty::Affine {open: 3, extend: 1};
+    let empty = Alignment {score: 0, s_aligned: "".to_string(), t_aligned: "".to_string(), s_range: 0..0, t_range: 0..0};
+    assert_eq!(global_alignment("", "", &dna, gap).unwrap(), empty);
+    assert_eq!(global_alignment("ACGT", "", &dna, gap).unwrap().to_string(), "-6\nACGT\n----");
+    assert_eq!(global_alignment("", "AC", &dna, gap).unwrap().to_string(), "-4\n--\nAC");
+  }
+
+  #[test]
+  fn it_should_align_dna_with_match_mismatch_scores() {
+    let dna = ScoringMatrix::match_mismatch(2, -3);
+    let gap = GapPenalty::Affine {open: 5, extend: 2};
+    let (s, t) = ("ACGTTGCATGCAAGTCCGATTAGCA", "ACGTGCATGGCAAGTCCGTTAGCAGG");
+    assert_alignment(s, t, &global_alignment(s, t, &dna, gap).unwrap(), &dna, gap);
+    assert_eq!(global_alignment("acgt", "ACGT", &dna, gap).unwrap().score, 8);
+  }
+
+  #[test]
+  fn it_should_return_error_when_symbol_is_not_scored() {
+    let blosum62 = ScoringMatrix::blosum62();
+    assert_eq!(global_alignment("MEANLY", "MEAN-LY", &blosum62, GapPenalty::Linear(5)).unwrap_err(), UnknownScoringSymbol('-'));
+    assert_eq!(global_alignment("MEANLYÆ", "MEANLY", &blosum62, GapPenalty::Linear(5)).unwrap_err(), UnknownScoringSymbol('Æ'));
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    assert_eq!(global_alignment("A-C", "AC", &dna, GapPenalty::Linear(1)).unwrap_err(), UnknownScoringSymbol('-'));
+  }
+
+  #[test]
+  fn it_should_return_error_when_gap_extension_costs_more_than_opening() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let gap = GapPenalty::Affine {open: 1, extend: 5};
+    assert_eq!(global_alignment("AAAAAA", "AA", &dna, gap).unwrap_err(), InvalidInputParameters);
+    let gap = GapPenalty::Affine {open: 5, extend: 5};
+    assert_eq!(global_alignment("AAAAAA", "AA", &dna, gap).unwrap().score, -18);
+  }
+
+  #[test]
+  fn it_should_align_locally_with_linear_gap_penalty() {
+    let pam250 = ScoringMatrix::pam250();
+    let (s, t) = ("MEANLYPRTEINSTRING", "PLEASANTLYEINSTEIN");
+    let alignment = local_alignment(s, t, &pam250, GapPenalty::Linear(5)).unwrap();
+    assert_eq!(alignment.score, 23);
+    assert_eq!((alignment.s_range.clone(), alignment.t_range.clone()), (0..17, 1..18));
+    assert_alignment(s, t, &alignment, &pam250, GapPenalty::Linear(5));
+  }
+
+  #[test]
+  fn it_should_align_locally_with_affine_gap_penalty() {
+    let blosum62 = ScoringMatrix::blosum62();
+    let gap = GapPenalty::Affine {open: 11, extend: 1};
+    let alignment = local_alignment("PLEASANTLY", "MEANLY", &blosum62, gap).unwrap();
+    assert_eq!(alignment.to_string(), "12\nLEAS\nMEAN");
+    assert_alignment("PLEASANTLY", "MEANLY", &alignment, &blosum62, gap);
+  }
+
+  #[test]
+  fn it_should_return_empty_local_alignment() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let alignment = local_alignment("AAAA", "CCCC", &dna, GapPenalty::Linear(1)).unwrap();
+    let empty = Alignment {score: 0, s_aligned: "".to_string(), t_aligned: "".to_string(), s_range: 0..0, t_range: 0..0};
+    assert_eq!(alignment, empty);
+    assert_eq!(local_alignment("", "ACGT", &dna, GapPenalty::Linear(1)).unwrap(), empty);
+  }
+
+  #[test]
+  fn it_should_find_conserved_domain_in_proteins() {
+    let mut seed = 11;
+    let domain = "MKTAYIAKQRQISFVKSHFSRQ";
+    let s = format!("{}{}{}", random_string(PROTEIN, 300, &mut seed), domain, random_string(PROTEIN, 200, &mut seed));
+    let t = format!("{}{}{}", random_string(PROTEIN, 150, &mut seed), domain, random_string(PROTEIN, 400, &mut seed));
+    let blosum62 = ScoringMatrix::blosum62();
+    let gap = GapPenalty::Affine {open: 11, extend: 1};
+    let alignment = local_alignment(&s, &t, &blosum62, gap).unwrap();
+    assert_alignment(&s, &t, &alignment, &blosum62, gap);
+    assert!(alignment.s_range.start <= 300 && alignment.s_range.end >= 300 + domain.len());
+    assert!(alignment.t_range.start <= 150 && alignment.t_range.end >= 150 + domain.len());
+  }
+
+  #[test]
+  fn it_should_pack_traceback() {
+    let mut traceback = Traceback::new(3, 3);
+    assert_eq!(traceback.cells.len(), 5);
+    traceback.set(1, 1, FROM_LEFT | UP_EXTENDS);
+    traceback.set(1, 2, FROM_START | LEFT_EXTENDS);
+    traceback.set(1, 1, FROM_UP);
+    assert_eq!((traceback.get(1, 1), traceback.get(1, 2), traceback.get(2, 2)), (FROM_UP, FROM_START | LEFT_EXTENDS, 0));
+  }
+
+  #[test]
+  fn it_should_align_motif_fitting_into_string() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let (s, t) = ("CCCGATTACAGGG", "GATTCA");
+    let alignment = align(s, t, &dna, GapPenalty::Linear(1), AlignmentMode::Fitting).unwrap();
+    assert_eq!(alignment.score, 5);
+    assert_eq!((alignment.s_range.clone(), alignment.t_range.clone()), (3..10, 0..6));
+    assert_alignment(s, t, &alignment, &dna, GapPenalty::Linear(1));
+  }
+
+  #[test]
+  fn it_should_align_overlapping_strings() {
+    let dna = ScoringMatrix::match_mismatch(1, -2);
+    let (s, t) = ("CTAAGGGATTCCGGTAATTAGACAG", "ATAGACCATATGTCAGTGACTGTGTAA");
+    let alignment = align(s, t, &dna, GapPenalty::Linear(2), AlignmentMode::Overlap).unwrap();
+    assert_eq!(alignment.score, 1);
+    assert_eq!((alignment.s_range.end, alignment.t_range.start), (s.len(), 0));
+    assert_alignment(s, t, &alignment, &dna, GapPenalty::Linear(2));
+  }
+
+  #[test]
+  fn it_should_align_fitting_and_overlap_as_brute_force() {
+    let dna = ScoringMatrix::match_mismatch(2, -3);
+    let gap = GapPenalty::Affine {open: 4, extend: 1};
+    let mut seed = 3;
+    for _ in 0..5 {
+      let (s, t) = (random_string("ACGT", 24, &mut seed), random_string("ACGT", 9, &mut seed));
+      let global = |i: usize, j: usize, k: usize, l: usize| global_alignment(&s[i..j], &t[k..l], &dna, gap).unwrap().score;
+
+      let fitting = align(&s, &t, &dna, gap, AlignmentMode::Fitting).unwrap();
+      let best = (0..s.len() + 1).flat_map(|i| (i..s.len() + 1).map(move |j| (i, j))).map(|(i, j)| global(i, j, 0, t.len())).max();
+      assert_eq!(Some(fitting.score), best);
+      assert_eq!(fitting.t_range, 0..t.len());
+      assert_alignment(&s, &t, &fitting, &dna, gap);
+
+      let overlap = align(&s, &t, &dna, gap, AlignmentMode::Overlap).unwrap();
+      let best = (0..s.len() + 1).flat_map(|i| (0..t.len() + 1).map(move |l| (i, l))).map(|(i, l)| global(i, s.len(), 0, l)).max();
+      assert_eq!(Some(overlap.score), best);
+      assert_eq!((overlap.s_range.end, overlap.t_range.start), (s.len(), 0));
+      assert_alignment(&s, &t, &overlap, &dna, gap);
+    }
+  }
+
+  #[test]
+  fn it_should_align_in_all_modes_consistently() {
+    let blosum62 = ScoringMatrix::blosum62();
+    let gap = GapPenalty::Linear(5);
+    let (s, t) = ("PLEASANTLY", "MEANLY");
+    let global = align(s, t, &blosum62, gap, AlignmentMode::Global).unwrap();
+    assert_eq!(global, global_alignment(s, t, &blosum62, gap).unwrap());
+    let local = align(s, t, &blosum62, gap, AlignmentMode::Local).unwrap();
+    assert_eq!(local, local_alignment(s, t, &blosum62, gap).unwrap());
+    for &mode in [AlignmentMode::Fitting, AlignmentMode::Overlap].iter() {
+      let alignment = align(s, t, &blosum62, gap, mode).unwrap();
+      assert!(global.score <= alignment.score && alignment.score <= local.score);
+    }
+  }
+}
@@ -2,7 +2,10 @@
 
 use std::fmt;
 use RosalindResult;
-use RosalindError::UnknownNucleotide;
+use RosalindError::ProfileStringsLengthError;
+use constants::GAP_SYMBOL;
+use fasta::FastaRecord;
+use seq::DnaBase;
 
 /// This structure contains profile of DNA strings
 #[allow(non_snake_case)]
@@ -20,10 +23,12 @@ impl fmt::Display for Profile {
   }
 }
 
-/// This function calculates profile by given DNA strings
+/// This function calculates profile by given DNA strings, strings may be aligned
+/// with gaps `-` which are not counted
 ///
 /// ## Examples
 /// ```
+/// use rosalind::RosalindError::ProfileStringsLengthError;
 /// use rosalind::cons::*;
 ///
 /// let dna_list = vec![
@@ -44,33 +49,82 @@ impl fmt::Display for Profile {
 /// };
 ///
 /// assert_eq!(profile(dna_list).unwrap(), expected_profile);
+/// assert_eq!(profile(vec!["AT-C", "A-GCA"]).unwrap_err(), ProfileStringsLengthError);
+/// assert_eq!(profile(vec![]).unwrap(), Profile {A: vec![], C: vec![], G: vec![], T: vec![]});
 /// ```
 pub fn profile(dna_list: Vec<&str>) -> RosalindResult<Profile> {
-    let buf_capacity = dna_list[0].len();
-    let mut profile = Profile {
+    let mut profile = empty_profile(dna_list.first().map_or(0, |dna| dna_length(dna)));
+
+    for dna in dna_list.iter() {
+        try!(add_to_profile(&mut profile, dna));
+    }
+
+    Ok(profile)
+}
+
+/// This function calculates profile by DNA strings of given FASTA records,
+/// the first record defines the length of profile
+///
+/// ## Examples
+/// ```
+/// use rosalind::cons::*;
+/// use rosalind::fasta::FastaReader;
+///
+/// let dataset = ">Rosalind_1\nATCC\n>Rosalind_2\nGGGC\n>Rosalind_3\nATGG\n";
+///
+/// let expected_profile = Profile {
+///     A: vec![2, 0, 0, 0],
+///     C: vec![0, 0, 1, 2],
+///     G: vec![1, 1, 2, 1],
+///     T: vec![0, 2, 0, 0],
+/// };
+///
+/// assert_eq!(profile_from_records(FastaReader::new(dataset.as_bytes())).unwrap(), expected_profile);
+/// ```
+pub fn profile_from_records<I>(records: I) -> RosalindResult<Profile>
+    where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+    let mut profile: Option<Profile> = None;
+
+    for record in records {
+        let record = try!(record);
+        let dna = record.seq.as_str();
+        try!(add_to_profile(profile.get_or_insert_with(|| empty_profile(dna_length(dna))), dna));
+    }
+
+    Ok(profile.unwrap_or_else(|| empty_profile(0)))
+}
+
+fn empty_profile(buf_capacity: usize) -> Profile {
+    Profile {
         A: vec![0; buf_capacity],
         C: vec![0; buf_capacity],
         G: vec![0; buf_capacity],
         T: vec![0; buf_capacity],
-    };
+    }
+}
 
-    for dna in dna_list.iter() {
-        for (i, nucleotide) in dna.chars().enumerate() {
-            match nucleotide {
-                'A' => profile.A[i] += 1,
-                'C' => profile.C[i] += 1,
-                'G' => profile.G[i] += 1,
-                'T' => profile.T[i] += 1,
-                '\n' => continue,
-                _ => return Err(UnknownNucleotide(nucleotide))
-            }
+fn dna_length(dna: &str) -> usize {
+    dna.chars().filter(|&nucleotide| nucleotide != '\n').count()
+}
+
+fn add_to_profile(profile: &mut Profile, dna: &str) -> RosalindResult<()> {
+    if dna_length(dna) != profile.A.len() { return Err(ProfileStringsLengthError); }
+
+    for (i, nucleotide) in dna.chars().filter(|&nucleotide| nucleotide != '\n').enumerate() {
+        if nucleotide == GAP_SYMBOL { continue; }
+        match try!(DnaBase::from_char(nucleotide)) {
+            DnaBase::A => profile.A[i] += 1,
+            DnaBase::C => profile.C[i] += 1,
+            DnaBase::G => profile.G[i] += 1,
+            DnaBase::T => profile.T[i] += 1,
         }
     }
 
-    Ok(profile)
+    Ok(())
 }
 
-/// This function calculates consensus string by given profile
+/// This function calculates consensus string by given profile, positions without
+/// nucleotides, e.g. columns of gaps in aligned strings, are skipped
 ///
 /// ## Examples
 /// ```
@@ -84,12 +138,15 @@ pub fn profile(dna_list: Vec<&str>) -> RosalindResult<Profile> {
 /// };
 ///
 /// assert_eq!(consensus(prof).unwrap(), "ATGCAACT");
+/// assert_eq!(consensus(profile(vec!["A-T", "A-C", "G-T"]).unwrap()).unwrap(), "AT");
 /// ```
 pub fn consensus(profile: Profile) -> RosalindResult<String> {
     let buf_capacity = profile.A.len();
     let mut buf: String = String::with_capacity(buf_capacity);
 
     for i in 0..buf_capacity {
+        if profile.A[i] + profile.C[i] + profile.G[i] + profile.T[i] == 0 { continue; }
+
         let mut consensus_char: char = 'A';
         let mut consensus_max: u32 = profile.A[i];
 
@@ -115,8 +172,9 @@ pub fn consensus(profile: Profile) -> RosalindResult<String> {
 
 #[cfg(test)]
 mod tests {
-    use super::{consensus, profile, Profile};
-    use super::super::RosalindError::UnknownNucleotide;
+    use super::{consensus, profile, profile_from_records, Profile};
+    use super::super::RosalindError::{UnknownNucleotide, ProfileStringsLengthError};
+    use fasta::FastaReader;
 
     #[test]
     fn it_should_return_error_when_unknown_nucleotide_found() {
@@ -146,6 +204,26 @@ mod tests {
         assert_eq!(prof, expected_profile);
     }
 
+    #[test]
+    fn it_should_calculate_profile_from_records() {
+        let dataset = ">Rosalind_1
+            ATCCAGCT
+            >Rosalind_2
+            GGGCAACT
+            >Rosalind_3
+            ATGGATCT";
+
+        let expected_profile = Profile {
+            A: vec![2, 0, 0, 0, 3, 1, 0, 0],
+            C: vec![0, 0, 1, 2, 0, 0, 3, 0],
+            G: vec![1, 1, 2, 1, 0, 1, 0, 0],
+            T: vec![0, 2, 0, 0, 0, 1, 0, 3],
+        };
+
+        let prof = profile_from_records(FastaReader::new(dataset.as_bytes())).unwrap();
+        assert_eq!(prof, expected_profile);
+    }
+
     #[test]
     fn it_should_calculate_consensus() {
         let prof = Profile {
@@ -160,4 +238,38 @@ mod tests {
         let cens = consensus(prof).unwrap();
         assert_eq!(cens, expected_consensus);
     }
+
+    #[test]
+    fn it_should_calculate_profile_of_aligned_strings() {
+        let expected_profile = Profile {
+            A: vec![2, 0, 0, 0, 1],
+            C: vec![0, 0, 0, 3, 0],
+            G: vec![0, 0, 1, 0, 0],
+            T: vec![0, 1, 0, 0, 1],
+        };
+
+        let prof = profile(vec!["AT-C-", "A-GCA", "---CT"]).unwrap();
+        assert_eq!(prof, expected_profile);
+        assert_eq!(consensus(prof).unwrap(), "ATGCA");
+    }
+
+    #[test]
+    fn it_should_skip_columns_of_gaps_in_consensus() {
+        assert_eq!(consensus(profile(vec!["A--T", "C--T", "A--G"]).unwrap()).unwrap(), "AT");
+    }
+
+    #[test]
+    fn it_should_return_error_when_strings_have_different_length() {
+        assert_eq!(profile(vec!["ACGT", "ACG"]).unwrap_err(), ProfileStringsLengthError);
+        assert_eq!(profile(vec!["ACG", "ACGT"]).unwrap_err(), ProfileStringsLengthError);
+
+        let dataset = ">Rosalind_1\nATCC\n>Rosalind_2\nGGGCA\n";
+        assert_eq!(profile_from_records(FastaReader::new(dataset.as_bytes())).unwrap_err(), ProfileStringsLengthError);
+    }
+
+    #[test]
+    fn it_should_calculate_empty_profile() {
+        assert_eq!(profile(vec![]).unwrap(), Profile {A: vec![], C: vec![], G: vec![], T: vec![]});
+        assert_eq!(consensus(profile(vec![]).unwrap()).unwrap(), "");
+    }
 }
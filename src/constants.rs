@@ -5,3 +5,21 @@ pub const CODON_STOP_SYMBOL: char = '\x00';
 
 /// First symbol of labels in datasets in FASTA format
 pub const FASTA_LABEL_SYMBOL: char = '>';
+
+/// Default width of sequence lines in datasets in FASTA format
+pub const FASTA_LINE_WIDTH: usize = 60;
+
+/// First symbol of labels in datasets in FASTQ format
+pub const FASTQ_LABEL_SYMBOL: char = '@';
+
+/// First symbol of lines separating sequence and quality in datasets in FASTQ format
+pub const FASTQ_SEPARATOR_SYMBOL: char = '+';
+
+/// Symbol of stop codons in translations of reading frames
+pub const PROTEIN_STOP_SYMBOL: char = '*';
+
+/// Symbol of amino acids which cannot be determined from ambiguous codons
+pub const UNKNOWN_AMINO_ACID_SYMBOL: char = 'X';
+
+/// Symbol of gaps in aligned strings
+pub const GAP_SYMBOL: char = '-';
@@ -0,0 +1,151 @@
+//! Module for `Error Correction in Reads`
+
+use std::collections::HashMap;
+use std::fmt;
+use RosalindResult;
+use fasta::{FastaReader, FastaRecord};
+use revc::reverse_complement_dna;
+use hamm::hamming_distance;
+
+/// This structure contains incorrect read with ID `id` and its correction
+#[derive(PartialEq, Debug)]
+pub struct Correction {
+  pub id: String,
+  pub old: String,
+  pub new: String,
+}
+
+impl fmt::Display for Correction {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}->{}", self.old, self.new)
+  }
+}
+
+/// This function finds corrections of reads in FASTA dataset
+///
+/// ## Examples
+/// ```
+/// use rosalind::corr::*;
+///
+/// let dataset = ">Rosalind_52
+///   TCATC
+///   >Rosalind_44
+///   TTCAT
+///   >Rosalind_68
+///   TCATC
+///   >Rosalind_28
+///   TGAAA
+///   >Rosalind_95
+///   GAGGA
+///   >Rosalind_66
+///   TTTCA
+///   >Rosalind_33
+///   ATCAA
+///   >Rosalind_21
+///   TTGAT
+///   >Rosalind_18
+///   TTTCC";
+///
+/// let corrections: Vec<String> = correct_reads_in_dataset(dataset).unwrap().iter().map(|c| c.to_string()).collect();
+/// assert_eq!(corrections, vec!["TTCAT->TTGAT", "GAGGA->GATGA", "TTTCC->TTTCA"]);
+/// ```
+pub fn correct_reads_in_dataset(dataset: &str) -> RosalindResult<Vec<Correction>> {
+  correct_reads(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function finds corrections of reads given by FASTA records. Reads which appear
+/// at least twice, possibly as reverse complements, are correct. Every other read is
+/// corrected into the correct read or its reverse complement at Hamming distance 1,
+/// reads without such correction are skipped. Corrections are ordered as incorrect
+/// reads and reads are case-insensitive.
+///
+/// ## Examples
+/// ```
+/// use rosalind::corr::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![
+///   Ok(FastaRecord::new("1", "", "GATTACA")),
+///   Ok(FastaRecord::new("2", "", "TGTAATC")),
+///   Ok(FastaRecord::new("3", "", "GATTAGA")),
+/// ];
+/// assert_eq!(correct_reads(records).unwrap(), vec![
+///   Correction {id: "3".to_string(), old: "GATTAGA".to_string(), new: "GATTACA".to_string()},
+/// ]);
+/// ```
+pub fn correct_reads<I>(records: I) -> RosalindResult<Vec<Correction>>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let mut reads = Vec::new();
+  for record in records {
+    let record = try!(record);
+    let read = record.seq.to_ascii_uppercase();
+    let complement = try!(reverse_complement_dna(&read));
+    reads.push((record.id, read, complement));
+  }
+
+  let mut counts: HashMap<&str, usize> = HashMap::new();
+  for (_, read, complement) in reads.iter() {
+    *counts.entry(read).or_insert(0) += 1;
+    if complement != read { *counts.entry(complement).or_insert(0) += 1; }
+  }
+
+  let correct_reads: Vec<&str> = reads
+    .iter()
+    .filter(|(_, read, _)| counts[&**read] > 1)
+    .flat_map(|(_, read, complement)| vec![&**read, &**complement])
+    .collect();
+
+  let mut corrections = Vec::new();
+  for (id, read, _) in reads.iter().filter(|(_, read, _)| counts[&**read] == 1) {
+    let correction = correct_reads
+      .iter()
+      .find(|correct_read| correct_read.len() == read.len() && hamming_distance(correct_read, read) == Ok(1));
+    if let Some(correct_read) = correction {
+      corrections.push(Correction {id: id.clone(), old: read.clone(), new: correct_read.to_string()});
+    }
+  }
+
+  Ok(corrections)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use fasta::FastaRecord;
+  use super::super::RosalindError::UnknownNucleotide;
+
+  fn records(reads: &[&str]) -> Vec<RosalindResult<FastaRecord>> {
+    reads.iter().enumerate().map(|(i, read)| Ok(FastaRecord::new(&i.to_string(), "", read))).collect()
+  }
+
+  #[test]
+  fn it_should_correct_reads() {
+    let reads = ["TCATC", "TTCAT", "TCATC", "TGAAA", "GAGGA", "TTTCA", "ATCAA", "TTGAT", "TTTCC"];
+    let corrections = correct_reads(records(&reads)).unwrap();
+    let corrections: Vec<(&str, String)> = corrections.iter().map(|c| (&*c.id, c.to_string())).collect();
+    assert_eq!(corrections, vec![("1", "TTCAT->TTGAT".to_string()), ("4", "GAGGA->GATGA".to_string()), ("8", "TTTCC->TTTCA".to_string())]);
+  }
+
+  #[test]
+  fn it_should_count_reverse_complements_as_correct_reads() {
+    let corrections = correct_reads(records(&["AACC", "GGTT", "AACG"])).unwrap();
+    assert_eq!(corrections.len(), 1);
+    assert_eq!(corrections[0].to_string(), "AACG->AACC");
+  }
+
+  #[test]
+  fn it_should_not_count_palindromic_read_twice() {
+    assert_eq!(correct_reads(records(&["GAATTC", "GAATTA"])).unwrap(), vec![]);
+  }
+
+  #[test]
+  fn it_should_correct_lowercase_reads() {
+    let corrections = correct_reads(records(&["gattaca", "GATTACA", "gattaga"])).unwrap();
+    assert_eq!(corrections[0].to_string(), "GATTAGA->GATTACA");
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(correct_reads(records(&["GATTACA", "GATTAZA"])).unwrap_err(), UnknownNucleotide('Z'));
+  }
+}
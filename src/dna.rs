@@ -3,6 +3,8 @@
 use std::fmt;
 use RosalindResult;
 use RosalindError::UnknownNucleotide;
+use seq::DnaSeq;
+use iupac;
 
 /// This structure contains amount of each nucleotide in DNA
 #[allow(non_snake_case)]
@@ -20,7 +22,25 @@ impl fmt::Display for DNANucleotides {
   }
 }
 
-/// This function calculates dna nucleotides
+/// This structure contains amount of each nucleotide in DNA with IUPAC
+/// ambiguity codes, all ambiguity codes are counted together
+#[allow(non_snake_case)]
+#[derive(PartialEq, Debug)]
+pub struct IupacDNANucleotides {
+  pub A: u32,
+  pub C: u32,
+  pub G: u32,
+  pub T: u32,
+  pub ambiguous: u32,
+}
+
+impl fmt::Display for IupacDNANucleotides {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} {} {} {} {}", self.A, self.C, self.G, self.T, self.ambiguous)
+  }
+}
+
+/// This function calculates dna nucleotides, nucleotides are case-insensitive
 ///
 /// # Examples
 /// ```
@@ -32,18 +52,40 @@ impl fmt::Display for DNANucleotides {
 /// assert_eq!(count_dna_nucleotides(dna).unwrap(), dna_nucleotides);
 /// assert_eq!(dna_nucleotides.to_string(), "20 12 17 21");
 /// assert_eq!(count_dna_nucleotides("\n").unwrap(), DNANucleotides {A: 0, C: 0, G: 0, T: 0});
+/// assert_eq!(count_dna_nucleotides("acgTT").unwrap(), DNANucleotides {A: 1, C: 1, G: 1, T: 2});
 /// assert_eq!(count_dna_nucleotides("Z").unwrap_err(), UnknownNucleotide('Z'));
 /// ```
 pub fn count_dna_nucleotides(dna: &str) -> RosalindResult<DNANucleotides> {
-  let mut dna_nucleotides = DNANucleotides {A: 0, C: 0, G: 0, T: 0};
+  Ok(try!(DnaSeq::new(dna)).count())
+}
+
+/// This function calculates dna nucleotides in DNA with IUPAC ambiguity codes
+///
+/// # Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::dna::*;
+///
+/// let dna = "AGCTNNNNRYAT";
+/// let dna_nucleotides = IupacDNANucleotides {A: 2, C: 1, G: 1, T: 2, ambiguous: 6};
+/// assert_eq!(count_dna_nucleotides_iupac(dna).unwrap(), dna_nucleotides);
+/// assert_eq!(dna_nucleotides.to_string(), "2 1 1 2 6");
+/// assert_eq!(count_dna_nucleotides_iupac("Z").unwrap_err(), UnknownNucleotide('Z'));
+/// ```
+pub fn count_dna_nucleotides_iupac(dna: &str) -> RosalindResult<IupacDNANucleotides> {
+  let mut dna_nucleotides = IupacDNANucleotides {A: 0, C: 0, G: 0, T: 0, ambiguous: 0};
   for nucleotide in dna.chars() {
-    match nucleotide {
+    match nucleotide.to_ascii_uppercase() {
       'A' => dna_nucleotides.A += 1,
       'C' => dna_nucleotides.C += 1,
       'G' => dna_nucleotides.G += 1,
       'T' => dna_nucleotides.T += 1,
+      'U' => return Err(UnknownNucleotide(nucleotide)),
       '\n' => continue,
-      _ => return Err(UnknownNucleotide(nucleotide))
+      _ => {
+        try!(iupac::nucleotide_mask(nucleotide));
+        dna_nucleotides.ambiguous += 1;
+      }
     }
   }
 
@@ -52,8 +94,8 @@ pub fn count_dna_nucleotides(dna: &str) -> RosalindResult<DNANucleotides> {
 
 #[cfg(test)]
 mod tests {
-  use super::DNANucleotides;
-  use super::count_dna_nucleotides;
+  use super::{DNANucleotides, IupacDNANucleotides};
+  use super::{count_dna_nucleotides, count_dna_nucleotides_iupac};
   use super::super::RosalindError::UnknownNucleotide;
 
   #[test]
@@ -78,4 +120,35 @@ mod tests {
   fn it_should_skip_new_line_symbol() {
     assert_eq!(count_dna_nucleotides("\n").unwrap(), DNANucleotides {A: 0, C: 0, G: 0, T: 0});
   }
+
+  #[test]
+  fn it_should_reject_ambiguity_codes_by_default() {
+    assert_eq!(count_dna_nucleotides("ACGN").unwrap_err(), UnknownNucleotide('N'));
+  }
+
+  #[test]
+  fn it_should_count_dna_nucleotides_with_ambiguity_codes() {
+    let dna = "NNNNAGCTRYSWKMBDHVNNNN\n";
+    let dna_nucleotides = IupacDNANucleotides {A: 1, C: 1, G: 1, T: 1, ambiguous: 18};
+    assert_eq!(count_dna_nucleotides_iupac(dna).unwrap(), dna_nucleotides);
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_ambiguity_code_found() {
+    assert_eq!(count_dna_nucleotides_iupac("ACGU").unwrap_err(), UnknownNucleotide('U'));
+    assert_eq!(count_dna_nucleotides_iupac("ACGZ").unwrap_err(), UnknownNucleotide('Z'));
+  }
+
+  #[test]
+  fn it_should_format_iupac_dna_nucleotides() {
+    let dna_nucleotides = IupacDNANucleotides {A: 1, C: 2, G: 3, T: 4, ambiguous: 5};
+    assert_eq!(dna_nucleotides.to_string(), "1 2 3 4 5");
+  }
+
+  #[test]
+  fn it_should_count_lowercase_dna_nucleotides() {
+    assert_eq!(count_dna_nucleotides("ACgtacGT").unwrap(), DNANucleotides {A: 2, C: 2, G: 2, T: 2});
+    assert_eq!(count_dna_nucleotides_iupac("ACgtnnNN").unwrap(),
+      IupacDNANucleotides {A: 1, C: 1, G: 1, T: 1, ambiguous: 4});
+  }
 }
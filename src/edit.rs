@@ -0,0 +1,184 @@
+//! Module for `Edit Distance`, `Edit Distance Alignment`
+
+use std::fmt;
+use RosalindResult;
+use RosalindError::InvalidInputParameters;
+use constants::GAP_SYMBOL;
+use fasta::{FastaReader, FastaRecord};
+
+/// This structure contains edit distance of two strings and their optimal alignment,
+/// gaps in aligned strings are denoted by `-`
+#[derive(PartialEq, Debug)]
+pub struct EditAlignment {
+  pub distance: usize,
+  pub s_aligned: String,
+  pub t_aligned: String,
+}
+
+impl fmt::Display for EditAlignment {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}\n{}\n{}", self.distance, self.s_aligned, self.t_aligned)
+  }
+}
+
+/// This function calculates edit (Levenshtein) distance between strings `s` and `t`,
+/// i.e. the minimal number of substitutions, insertions and deletions of symbols
+/// transforming `s` into `t`
+///
+/// ## Examples
+/// ```
+/// use rosalind::edit::*;
+///
+/// assert_eq!(edit_distance("PLEASANTLY", "MEANLY"), 5);
+/// assert_eq!(edit_distance("GATTACA", ""), 7);
+/// ```
+pub fn edit_distance(s: &str, t: &str) -> usize {
+  let t = t.as_bytes();
+  let mut row: Vec<usize> = (0..(t.len() + 1)).collect();
+  for (i, s_symbol) in s.bytes().enumerate() {
+    let mut diagonal = row[0];
+    row[0] = i + 1;
+    for j in 0..t.len() {
+      let up = row[j + 1];
+      row[j + 1] = (diagonal + if s_symbol == t[j] { 0 } else { 1 }).min(up + 1).min(row[j] + 1);
+      diagonal = up;
+    }
+  }
+  row[t.len()]
+}
+
+/// This function finds optimal alignment of strings `s` and `t` with respect to
+/// edit distance, i.e. the number of mismatched symbols and gaps in the alignment
+/// is the edit distance
+///
+/// ## Examples
+/// ```
+/// use rosalind::edit::*;
+///
+/// let alignment = edit_alignment("PRETTY", "PRTTEIN");
+/// assert_eq!(alignment.distance, 4);
+/// assert_eq!(alignment.s_aligned.len(), alignment.t_aligned.len());
+/// assert_eq!(alignment.s_aligned.replace("-", ""), "PRETTY");
+/// assert_eq!(alignment.t_aligned.replace("-", ""), "PRTTEIN");
+/// ```
+pub fn edit_alignment(s: &str, t: &str) -> EditAlignment {
+  let (s, t) = (s.as_bytes(), t.as_bytes());
+  let width = t.len() + 1;
+  let mut table = vec![0; (s.len() + 1) * width];
+  for (j, distance) in table.iter_mut().enumerate().take(width) { *distance = j; }
+  for i in 0..s.len() {
+    table[(i + 1) * width] = i + 1;
+    for j in 0..t.len() {
+      let substitution = table[i * width + j] + if s[i] == t[j] { 0 } else { 1 };
+      let deletion = table[i * width + j + 1] + 1;
+      let insertion = table[(i + 1) * width + j] + 1;
+      table[(i + 1) * width + j + 1] = substitution.min(deletion).min(insertion);
+    }
+  }
+
+  let (mut s_aligned, mut t_aligned) = (Vec::new(), Vec::new());
+  let (mut i, mut j) = (s.len(), t.len());
+  while i > 0 || j > 0 {
+    let distance = table[i * width + j];
+    if i > 0 && j > 0 && distance == table[(i - 1) * width + j - 1] + if s[i - 1] == t[j - 1] { 0 } else { 1 } {
+      i -= 1;
+      j -= 1;
+      s_aligned.push(s[i] as char);
+      t_aligned.push(t[j] as char);
+    } else if i > 0 && distance == table[(i - 1) * width + j] + 1 {
+      i -= 1;
+      s_aligned.push(s[i] as char);
+      t_aligned.push(GAP_SYMBOL);
+    } else {
+      j -= 1;
+      s_aligned.push(GAP_SYMBOL);
+      t_aligned.push(t[j] as char);
+    }
+  }
+
+  EditAlignment {
+    distance: table[s.len() * width + t.len()],
+    s_aligned: s_aligned.into_iter().rev().collect(),
+    t_aligned: t_aligned.into_iter().rev().collect(),
+  }
+}
+
+/// This function finds optimal alignment of two strings in FASTA dataset with respect
+/// to edit distance
+///
+/// ## Examples
+/// ```
+/// use rosalind::edit::*;
+///
+/// let dataset = ">Rosalind_43
+///   PRETTY
+///   >Rosalind_97
+///   PRTTEIN";
+///
+/// assert_eq!(edit_alignment_in_dataset(dataset).unwrap().distance, 4);
+/// ```
+pub fn edit_alignment_in_dataset(dataset: &str) -> RosalindResult<EditAlignment> {
+  edit_alignment_in_records(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function finds optimal alignment of sequences of two FASTA records with respect
+/// to edit distance, the records must come in a pair
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::edit::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![Ok(FastaRecord::new("s", "", "GATTACA")), Ok(FastaRecord::new("t", "", "GATACA"))];
+/// assert_eq!(edit_alignment_in_records(records).unwrap().to_string(), "1\nGATTACA\nGA-TACA");
+/// assert_eq!(edit_alignment_in_records(vec![]).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn edit_alignment_in_records<I>(records: I) -> RosalindResult<EditAlignment>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+  if records.len() != 2 { return Err(InvalidInputParameters); }
+  Ok(edit_alignment(&records[0].seq, &records[1].seq))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_edit_alignment(s: &str, t: &str, alignment: &EditAlignment) {
+    assert_eq!(alignment.distance, edit_distance(s, t));
+    assert_eq!(alignment.s_aligned.len(), alignment.t_aligned.len());
+    assert_eq!(alignment.s_aligned.replace("-", ""), s);
+    assert_eq!(alignment.t_aligned.replace("-", ""), t);
+    let distance = alignment.s_aligned.chars().zip(alignment.t_aligned.chars()).filter(|&(a, b)| a != b).count();
+    assert_eq!(distance, alignment.distance);
+  }
+
+  #[test]
+  fn it_should_return_edit_distance() {
+    assert_eq!(edit_distance("PLEASANTLY", "MEANLY"), 5);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("GATTACA", "GATTACA"), 0);
+    assert_eq!(edit_distance("", ""), 0);
+    assert_eq!(edit_distance("", "ACGT"), 4);
+  }
+
+  #[test]
+  fn it_should_return_edit_alignment() {
+    let alignment = edit_alignment("PRETTY", "PRTTEIN");
+    assert_edit_alignment("PRETTY", "PRTTEIN", &alignment);
+    assert_eq!(alignment.distance, 4);
+  }
+
+  #[test]
+  fn it_should_align_strings_of_different_length() {
+    for &(s, t) in [("PLEASANTLY", "MEANLY"), ("", "ACGT"), ("ACGT", ""), ("AAAAAAAA", "A"), ("GCTAGC", "TAGGCTA")].iter() {
+      assert_edit_alignment(s, t, &edit_alignment(s, t));
+    }
+  }
+
+  #[test]
+  fn it_should_display_edit_alignment() {
+    assert_eq!(edit_alignment("ACGT", "ACT").to_string(), "1\nACGT\nAC-T");
+  }
+}
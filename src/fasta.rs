@@ -0,0 +1,465 @@
+//! Module for reading and writing datasets in FASTA format
+
+use std::collections::HashSet;
+use std::io::{BufRead, Write};
+use RosalindResult;
+use RosalindError::{
+  FastaMissingLabel,
+  FastaEmptyLabel,
+  FastaEmptyRecord,
+  FastaDuplicateId,
+  FastaIllegalCharacter,
+};
+use constants::{FASTA_LABEL_SYMBOL, FASTA_LINE_WIDTH};
+
+fn is_fasta_label(s: &str) -> bool {
+  s.trim_start().starts_with(FASTA_LABEL_SYMBOL)
+}
+
+fn is_fasta_sequence_symbol(c: char) -> bool {
+  c.is_ascii_alphabetic() || c == '*' || c == '-'
+}
+
+/// This structure contains a single record of FASTA dataset: identifier and
+/// description from the label line and the sequence
+#[derive(PartialEq, Debug, Clone)]
+pub struct FastaRecord {
+  pub id: String,
+  pub description: String,
+  pub seq: String,
+}
+
+impl FastaRecord {
+  /// This function creates FASTA record from identifier, description and sequence
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::fasta::*;
+  ///
+  /// let record = FastaRecord::new("Rosalind_1", "chromosome 1", "CCTGCGGAAG");
+  /// assert_eq!(record.id, "Rosalind_1");
+  /// assert_eq!(record.description, "chromosome 1");
+  /// assert_eq!(record.seq, "CCTGCGGAAG");
+  /// ```
+  pub fn new(id: &str, description: &str, seq: &str) -> FastaRecord {
+    FastaRecord {
+      id: id.to_string(),
+      description: description.to_string(),
+      seq: seq.to_string(),
+    }
+  }
+
+  fn from_label(label: &str) -> FastaRecord {
+    let label = label.trim_start_matches(FASTA_LABEL_SYMBOL).trim();
+    let mut parts = label.splitn(2, char::is_whitespace);
+    let id = parts.next().unwrap_or("");
+    let description = parts.next().unwrap_or("").trim();
+    FastaRecord::new(id, description, "")
+  }
+}
+
+/// This structure reads FASTA records one by one from any buffered reader,
+/// so the whole dataset never has to be kept in memory
+///
+/// ## Examples
+/// ```
+/// use rosalind::fasta::*;
+///
+/// let dataset = ">Rosalind_1 first record
+///   CCTGCGGAAG
+///   TCCCACTAAT
+///   >Rosalind_2
+///   CCATCGGTAG";
+///
+/// let records: Vec<FastaRecord> = FastaReader::new(dataset.as_bytes())
+///   .collect::<Result<_, _>>()
+///   .unwrap();
+///
+/// assert_eq!(records, vec![
+///   FastaRecord::new("Rosalind_1", "first record", "CCTGCGGAAGTCCCACTAAT"),
+///   FastaRecord::new("Rosalind_2", "", "CCATCGGTAG"),
+/// ]);
+/// ```
+pub struct FastaReader<R: BufRead> {
+  reader: R,
+  buf: String,
+  label: Option<(String, usize)>,
+  line_number: usize,
+  strict: bool,
+  ids: HashSet<String>,
+  done: bool,
+}
+
+impl<R: BufRead> FastaReader<R> {
+  /// This function creates FASTA reader on top of buffered reader
+  pub fn new(reader: R) -> FastaReader<R> {
+    FastaReader {
+      reader,
+      buf: String::new(),
+      label: None,
+      line_number: 0,
+      strict: false,
+      ids: HashSet::new(),
+      done: false,
+    }
+  }
+
+  /// This function creates FASTA reader which rejects malformed datasets:
+  /// sequence before the first label, labels without identifier, records
+  /// without sequence, duplicate identifiers and illegal sequence symbols.
+  /// Errors carry line (and column) numbers of the problem, both start from 1.
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::RosalindError::{FastaDuplicateId, FastaIllegalCharacter};
+  /// use rosalind::fasta::*;
+  ///
+  /// let mut reader = FastaReader::strict(">Rosalind_1\nCCTG\nCC1G\n".as_bytes());
+  /// assert_eq!(reader.next().unwrap().unwrap_err(), FastaIllegalCharacter('1', 3, 3));
+  ///
+  /// let mut reader = FastaReader::strict(">Rosalind_1\nCCTG\n>Rosalind_1\nCCAT\n".as_bytes());
+  /// assert_eq!(reader.next().unwrap().unwrap(), FastaRecord::new("Rosalind_1", "", "CCTG"));
+  /// assert_eq!(reader.next().unwrap().unwrap_err(), FastaDuplicateId("Rosalind_1".to_string(), 3));
+  /// ```
+  pub fn strict(reader: R) -> FastaReader<R> {
+    let mut fasta_reader = FastaReader::new(reader);
+    fasta_reader.strict = true;
+    fasta_reader
+  }
+
+  fn read_record(&mut self) -> RosalindResult<Option<FastaRecord>> {
+    let mut record: Option<(FastaRecord, usize)> = match self.label.take() {
+      Some((label, line_number)) => Some((try!(self.parse_label(&label, line_number)), line_number)),
+      None => None,
+    };
+
+    loop {
+      self.buf.clear();
+      if try!(self.reader.read_line(&mut self.buf)) == 0 {
+        self.done = true;
+        return self.complete_record(record);
+      }
+      self.line_number += 1;
+
+      let line = self.buf.trim();
+      if line.is_empty() { continue; }
+
+      if is_fasta_label(line) {
+        if record.is_some() {
+          self.label = Some((line.to_string(), self.line_number));
+          return self.complete_record(record);
+        }
+        let label = line.to_string();
+        record = Some((try!(self.parse_label(&label, self.line_number)), self.line_number));
+      } else {
+        if self.strict {
+          if record.is_none() { return Err(FastaMissingLabel(self.line_number)); }
+
+          let indent = self.buf.chars().take_while(|c| c.is_whitespace()).count();
+          if let Some((i, c)) = line.chars().enumerate().find(|&(_, c)| !is_fasta_sequence_symbol(c)) {
+            return Err(FastaIllegalCharacter(c, self.line_number, indent + i + 1));
+          }
+        }
+        record.get_or_insert_with(|| (FastaRecord::new("", "", ""), 0)).0.seq.push_str(line);
+      }
+    }
+  }
+
+  fn parse_label(&mut self, label: &str, line_number: usize) -> RosalindResult<FastaRecord> {
+    let record = FastaRecord::from_label(label);
+    if self.strict {
+      if record.id.is_empty() { return Err(FastaEmptyLabel(line_number)); }
+      if !self.ids.insert(record.id.clone()) {
+        return Err(FastaDuplicateId(record.id, line_number));
+      }
+    }
+    Ok(record)
+  }
+
+  fn complete_record(&self, record: Option<(FastaRecord, usize)>) -> RosalindResult<Option<FastaRecord>> {
+    match record {
+      Some((ref record, line_number)) if self.strict && record.seq.is_empty() =>
+        Err(FastaEmptyRecord(record.id.clone(), line_number)),
+      Some((record, _)) => Ok(Some(record)),
+      None => Ok(None),
+    }
+  }
+}
+
+impl<R: BufRead> Iterator for FastaReader<R> {
+  type Item = RosalindResult<FastaRecord>;
+
+  fn next(&mut self) -> Option<RosalindResult<FastaRecord>> {
+    if self.done { return None; }
+
+    match self.read_record() {
+      Ok(record) => record.map(Ok),
+      Err(err) => {
+        self.done = true;
+        Some(Err(err))
+      }
+    }
+  }
+}
+
+/// This structure writes FASTA records into any writer, wrapping sequence
+/// lines at the configured width
+///
+/// ## Examples
+/// ```
+/// use rosalind::fasta::*;
+///
+/// let mut writer = FastaWriter::with_line_width(Vec::new(), Some(4));
+/// writer.write_record(&FastaRecord::new("Rosalind_1", "first record", "CCTGCGGAAG")).unwrap();
+/// writer.write_record(&FastaRecord::new("Rosalind_2", "", "CCAT")).unwrap();
+///
+/// let dataset = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(dataset, ">Rosalind_1 first record\nCCTG\nCGGA\nAG\n>Rosalind_2\nCCAT\n");
+/// ```
+pub struct FastaWriter<W: Write> {
+  writer: W,
+  line_width: Option<usize>,
+}
+
+impl<W: Write> FastaWriter<W> {
+  /// This function creates FASTA writer which wraps sequence lines at
+  /// `FASTA_LINE_WIDTH` symbols
+  pub fn new(writer: W) -> FastaWriter<W> {
+    FastaWriter::with_line_width(writer, Some(FASTA_LINE_WIDTH))
+  }
+
+  /// This function creates FASTA writer which wraps sequence lines at
+  /// `line_width` symbols, `None` (or zero width) writes every sequence
+  /// into a single line
+  pub fn with_line_width(writer: W, line_width: Option<usize>) -> FastaWriter<W> {
+    FastaWriter {
+      writer,
+      line_width: line_width.and_then(|width| if width > 0 { Some(width) } else { None }),
+    }
+  }
+
+  /// This function writes label line and sequence lines of the record
+  pub fn write_record(&mut self, record: &FastaRecord) -> RosalindResult<()> {
+    try!(write!(self.writer, "{}{}", FASTA_LABEL_SYMBOL, record.id));
+    if !record.description.is_empty() {
+      try!(write!(self.writer, " {}", record.description));
+    }
+    try!(self.writer.write_all(b"\n"));
+
+    if record.seq.is_empty() { return Ok(()); }
+
+    let seq = record.seq.as_bytes();
+    let line_width = self.line_width.unwrap_or(seq.len());
+    for line in seq.chunks(line_width) {
+      try!(self.writer.write_all(line));
+      try!(self.writer.write_all(b"\n"));
+    }
+
+    Ok(())
+  }
+
+  /// This function flushes underlying writer
+  pub fn flush(&mut self) -> RosalindResult<()> {
+    try!(self.writer.flush());
+    Ok(())
+  }
+
+  /// This function returns underlying writer
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use super::super::RosalindError::{
+    FastaMissingLabel,
+    FastaEmptyLabel,
+    FastaEmptyRecord,
+    FastaDuplicateId,
+    FastaIllegalCharacter,
+  };
+
+  #[test]
+  fn it_should_determine_fasta_label() {
+    assert_eq!(is_fasta_label(">Rosalind_1"), true);
+    assert_eq!(is_fasta_label("  >Rosalind_1"), true);
+    assert_eq!(is_fasta_label("CCTGCGGAAG"), false);
+    assert_eq!(is_fasta_label("CCTG>CGGAAG"), false);
+  }
+
+  #[test]
+  fn it_should_read_fasta_records() {
+    let dataset = ">Rosalind_1 Homo sapiens
+      CCTGCGGAAG
+      TCCCACTAAT
+      >Rosalind_2
+      CCATCGGTAG
+      ATATCCATTT";
+
+    let records: Vec<FastaRecord> = FastaReader::new(dataset.as_bytes())
+      .collect::<Result<_, _>>()
+      .unwrap();
+
+    assert_eq!(records, vec![
+      FastaRecord::new("Rosalind_1", "Homo sapiens", "CCTGCGGAAGTCCCACTAAT"),
+      FastaRecord::new("Rosalind_2", "", "CCATCGGTAGATATCCATTT"),
+    ]);
+  }
+
+  #[test]
+  fn it_should_skip_blank_lines() {
+    let dataset = "\n>Rosalind_1\n\nCCTG\n\n>Rosalind_2\nCCAT\n\n";
+    let records: Vec<FastaRecord> = FastaReader::new(dataset.as_bytes())
+      .collect::<Result<_, _>>()
+      .unwrap();
+
+    assert_eq!(records, vec![
+      FastaRecord::new("Rosalind_1", "", "CCTG"),
+      FastaRecord::new("Rosalind_2", "", "CCAT"),
+    ]);
+  }
+
+  #[test]
+  fn it_should_read_record_without_sequence() {
+    let mut reader = FastaReader::new(">Rosalind_1\n>Rosalind_2\nCCAT".as_bytes());
+    assert_eq!(reader.next().unwrap().unwrap(), FastaRecord::new("Rosalind_1", "", ""));
+    assert_eq!(reader.next().unwrap().unwrap(), FastaRecord::new("Rosalind_2", "", "CCAT"));
+    assert!(reader.next().is_none());
+  }
+
+  #[test]
+  fn it_should_return_nothing_for_empty_dataset() {
+    assert!(FastaReader::new("".as_bytes()).next().is_none());
+  }
+
+  fn read_strict(dataset: &str) -> Result<Vec<FastaRecord>, ::RosalindError> {
+    FastaReader::strict(dataset.as_bytes()).collect()
+  }
+
+  #[test]
+  fn it_should_read_well_formed_dataset_in_strict_mode() {
+    let dataset = ">Rosalind_1 Homo sapiens
+      CCTGCGGAAG
+      TCCCACTAAT
+
+      >Rosalind_2
+      MAMAPRTEINSTRING*
+      >Rosalind_3
+      CC-TG";
+
+    assert_eq!(read_strict(dataset).unwrap(), vec![
+      FastaRecord::new("Rosalind_1", "Homo sapiens", "CCTGCGGAAGTCCCACTAAT"),
+      FastaRecord::new("Rosalind_2", "", "MAMAPRTEINSTRING*"),
+      FastaRecord::new("Rosalind_3", "", "CC-TG"),
+    ]);
+  }
+
+  #[test]
+  fn it_should_return_error_when_sequence_precedes_label() {
+    assert_eq!(read_strict("\nCCTG\n>Rosalind_1\nCCAT").unwrap_err(), FastaMissingLabel(2));
+  }
+
+  #[test]
+  fn it_should_read_sequence_without_label_in_lenient_mode() {
+    let records: Vec<FastaRecord> = FastaReader::new("CCTG\n>Rosalind_1\nCCAT".as_bytes())
+      .collect::<Result<_, _>>()
+      .unwrap();
+    assert_eq!(records, vec![
+      FastaRecord::new("", "", "CCTG"),
+      FastaRecord::new("Rosalind_1", "", "CCAT"),
+    ]);
+  }
+
+  #[test]
+  fn it_should_return_error_when_label_has_no_id() {
+    assert_eq!(read_strict(">Rosalind_1\nCCTG\n> \nCCAT").unwrap_err(), FastaEmptyLabel(3));
+  }
+
+  #[test]
+  fn it_should_return_error_when_record_is_empty() {
+    assert_eq!(read_strict(">Rosalind_1\n>Rosalind_2\nCCAT").unwrap_err(),
+      FastaEmptyRecord("Rosalind_1".to_string(), 1));
+    assert_eq!(read_strict(">Rosalind_1\nCCAT\n\n>Rosalind_2\n\n").unwrap_err(),
+      FastaEmptyRecord("Rosalind_2".to_string(), 4));
+  }
+
+  #[test]
+  fn it_should_return_error_when_id_is_duplicated() {
+    assert_eq!(read_strict(">Rosalind_1\nCCTG\n>Rosalind_2\nCCAT\n>Rosalind_1 copy\nCCAT").unwrap_err(),
+      FastaDuplicateId("Rosalind_1".to_string(), 5));
+  }
+
+  #[test]
+  fn it_should_return_error_when_illegal_character_found() {
+    assert_eq!(read_strict(">Rosalind_1\nCCTG\n  CCA TG").unwrap_err(), FastaIllegalCharacter(' ', 3, 6));
+    assert_eq!(read_strict(">Rosalind_1\nCC>TG").unwrap_err(), FastaIllegalCharacter('>', 2, 3));
+  }
+
+  #[test]
+  fn it_should_stop_reading_after_error() {
+    let mut reader = FastaReader::strict(">Rosalind_1\nCC1G\n>Rosalind_2\nCCAT".as_bytes());
+    assert_eq!(reader.next().unwrap().unwrap_err(), FastaIllegalCharacter('1', 2, 3));
+    assert!(reader.next().is_none());
+  }
+
+  fn write_records(records: &[FastaRecord], line_width: Option<usize>) -> String {
+    let mut writer = FastaWriter::with_line_width(Vec::new(), line_width);
+    for record in records {
+      writer.write_record(record).unwrap();
+    }
+    String::from_utf8(writer.into_inner()).unwrap()
+  }
+
+  #[test]
+  fn it_should_wrap_sequence_lines() {
+    let records = vec![FastaRecord::new("Rosalind_1", "Homo sapiens", "CCTGCGGAAGTCCCACTAAT")];
+    assert_eq!(write_records(&records, Some(8)),
+      ">Rosalind_1 Homo sapiens\nCCTGCGGA\nAGTCCCAC\nTAAT\n");
+    assert_eq!(write_records(&records, Some(10)),
+      ">Rosalind_1 Homo sapiens\nCCTGCGGAAG\nTCCCACTAAT\n");
+  }
+
+  #[test]
+  fn it_should_not_wrap_sequence_lines() {
+    let records = vec![FastaRecord::new("Rosalind_1", "", "CCTGCGGAAGTCCCACTAAT")];
+    assert_eq!(write_records(&records, None), ">Rosalind_1\nCCTGCGGAAGTCCCACTAAT\n");
+    assert_eq!(write_records(&records, Some(0)), ">Rosalind_1\nCCTGCGGAAGTCCCACTAAT\n");
+  }
+
+  #[test]
+  fn it_should_wrap_sequence_lines_at_default_width() {
+    let seq: String = ::std::iter::repeat("ACGT").take(20).collect();
+    let mut writer = FastaWriter::new(Vec::new());
+    writer.write_record(&FastaRecord::new("Rosalind_1", "", &seq)).unwrap();
+
+    let dataset = String::from_utf8(writer.into_inner()).unwrap();
+    let lines: Vec<&str> = dataset.lines().collect();
+    assert_eq!(lines, vec![">Rosalind_1", &seq[..60], &seq[60..]]);
+  }
+
+  #[test]
+  fn it_should_write_record_without_sequence() {
+    let records = vec![FastaRecord::new("Rosalind_1", "", "")];
+    assert_eq!(write_records(&records, Some(60)), ">Rosalind_1\n");
+  }
+
+  #[test]
+  fn it_should_round_trip_fasta_records() {
+    let records = vec![
+      FastaRecord::new("Rosalind_1", "Homo sapiens", "CCTGCGGAAGTCCCACTAAT"),
+      FastaRecord::new("Rosalind_2", "", "CCATCGGTAG"),
+      FastaRecord::new("Rosalind_3", "empty", ""),
+    ];
+
+    for line_width in vec![None, Some(1), Some(7), Some(60)] {
+      let dataset = write_records(&records, line_width);
+      let read_records: Vec<FastaRecord> = FastaReader::new(dataset.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+      assert_eq!(read_records, records);
+    }
+  }
+}
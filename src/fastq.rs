@@ -0,0 +1,352 @@
+//! Module for reading and writing datasets in FASTQ format
+
+use std::io::{BufRead, Write};
+use RosalindResult;
+use RosalindError::{FastqParseError, FastqQualityLengthError, InvalidQualityScore};
+use constants::{FASTQ_LABEL_SYMBOL, FASTQ_SEPARATOR_SYMBOL};
+
+/// This enumeration contains encodings of Phred quality scores
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum QualityEncoding {
+  /// Scores are shifted by 33, used by Sanger and Illumina 1.8+
+  Phred33,
+  /// Scores are shifted by 64, used by Illumina 1.3 - 1.7
+  Phred64,
+}
+
+impl QualityEncoding {
+  fn offset(&self) -> u8 {
+    match *self {
+      QualityEncoding::Phred33 => 33,
+      QualityEncoding::Phred64 => 64,
+    }
+  }
+}
+
+/// This function decodes quality string into numeric Phred scores
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidQualityScore;
+/// use rosalind::fastq::*;
+///
+/// assert_eq!(decode_quality("!+5?I", QualityEncoding::Phred33).unwrap(), vec![0, 10, 20, 30, 40]);
+/// assert_eq!(decode_quality("@JT^h", QualityEncoding::Phred64).unwrap(), vec![0, 10, 20, 30, 40]);
+/// assert_eq!(decode_quality("5?", QualityEncoding::Phred64).unwrap_err(), InvalidQualityScore('5'));
+/// ```
+pub fn decode_quality(qual: &str, encoding: QualityEncoding) -> RosalindResult<Vec<u8>> {
+  let offset = encoding.offset();
+  qual.chars().map(|symbol| {
+    if symbol < offset as char || symbol > '~' { return Err(InvalidQualityScore(symbol)); }
+    Ok(symbol as u8 - offset)
+  }).collect()
+}
+
+/// This function encodes numeric Phred scores into quality string
+///
+/// ## Examples
+/// ```
+/// use rosalind::fastq::*;
+///
+/// assert_eq!(encode_quality(&[0, 10, 20, 30, 40], QualityEncoding::Phred33).unwrap(), "!+5?I");
+/// assert_eq!(encode_quality(&[0, 10, 20, 30, 40], QualityEncoding::Phred64).unwrap(), "@JT^h");
+/// ```
+pub fn encode_quality(scores: &[u8], encoding: QualityEncoding) -> RosalindResult<String> {
+  let offset = encoding.offset();
+  scores.iter().map(|&score| {
+    let symbol = score.saturating_add(offset);
+    if symbol > b'~' { return Err(InvalidQualityScore(symbol as char)); }
+    Ok(symbol as char)
+  }).collect()
+}
+
+/// This structure contains a single record of FASTQ dataset: identifier and
+/// description from the label line, the sequence and its quality string
+#[derive(PartialEq, Debug, Clone)]
+pub struct FastqRecord {
+  pub id: String,
+  pub description: String,
+  pub seq: String,
+  pub qual: String,
+}
+
+impl FastqRecord {
+  /// This function creates FASTQ record from identifier, description, sequence and quality string
+  pub fn new(id: &str, description: &str, seq: &str, qual: &str) -> FastqRecord {
+    FastqRecord {
+      id: id.to_string(),
+      description: description.to_string(),
+      seq: seq.to_string(),
+      qual: qual.to_string(),
+    }
+  }
+
+  /// This function decodes quality string of the record into numeric Phred scores
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::fastq::*;
+  ///
+  /// let record = FastqRecord::new("read_1", "", "ACGT", "!+5?");
+  /// assert_eq!(record.quality_scores(QualityEncoding::Phred33).unwrap(), vec![0, 10, 20, 30]);
+  /// ```
+  pub fn quality_scores(&self, encoding: QualityEncoding) -> RosalindResult<Vec<u8>> {
+    decode_quality(&self.qual, encoding)
+  }
+}
+
+/// This structure reads FASTQ records one by one from any buffered reader.
+/// Every record takes four lines: `@` label, sequence, `+` separator and quality.
+///
+/// ## Examples
+/// ```
+/// use rosalind::dna::*;
+/// use rosalind::gc::*;
+/// use rosalind::fastq::*;
+///
+/// let dataset = "@read_1 lane 1\nAGCTATAG\n+\nIIIIIIII\n@read_2\nGGCC\n+read_2\n!!II\n";
+/// let records: Vec<FastqRecord> = FastqReader::new(dataset.as_bytes())
+///   .collect::<Result<_, _>>()
+///   .unwrap();
+///
+/// assert_eq!(records[0], FastqRecord::new("read_1", "lane 1", "AGCTATAG", "IIIIIIII"));
+/// assert_eq!(records[1], FastqRecord::new("read_2", "", "GGCC", "!!II"));
+/// assert_eq!(count_dna_nucleotides(&records[0].seq).unwrap(), DNANucleotides {A: 3, C: 1, G: 2, T: 2});
+/// assert_eq!(gc_content(&records[1].seq).unwrap(), 100f32);
+/// ```
+pub struct FastqReader<R: BufRead> {
+  reader: R,
+  buf: String,
+  line_number: usize,
+  done: bool,
+}
+
+impl<R: BufRead> FastqReader<R> {
+  /// This function creates FASTQ reader on top of buffered reader
+  pub fn new(reader: R) -> FastqReader<R> {
+    FastqReader {
+      reader,
+      buf: String::new(),
+      line_number: 0,
+      done: false,
+    }
+  }
+
+  fn read_line(&mut self) -> RosalindResult<Option<String>> {
+    self.buf.clear();
+    if try!(self.reader.read_line(&mut self.buf)) == 0 { return Ok(None); }
+    self.line_number += 1;
+    Ok(Some(self.buf.trim().to_string()))
+  }
+
+  fn read_record_line(&mut self) -> RosalindResult<String> {
+    match try!(self.read_line()) {
+      Some(line) => Ok(line),
+      None => Err(FastqParseError(self.line_number + 1)),
+    }
+  }
+
+  fn read_record(&mut self) -> RosalindResult<Option<FastqRecord>> {
+    let label = loop {
+      match try!(self.read_line()) {
+        Some(ref line) if line.is_empty() => continue,
+        Some(line) => break line,
+        None => return Ok(None),
+      }
+    };
+
+    if !label.starts_with(FASTQ_LABEL_SYMBOL) { return Err(FastqParseError(self.line_number)); }
+    let label_line_number = self.line_number;
+    let mut parts = label[1..].trim().splitn(2, char::is_whitespace);
+    let id = parts.next().unwrap_or("").to_string();
+    let description = parts.next().unwrap_or("").trim().to_string();
+
+    let seq = try!(self.read_record_line());
+    let separator = try!(self.read_record_line());
+    if !separator.starts_with(FASTQ_SEPARATOR_SYMBOL) { return Err(FastqParseError(self.line_number)); }
+    let qual = try!(self.read_record_line());
+
+    if seq.len() != qual.len() { return Err(FastqQualityLengthError(id, label_line_number)); }
+
+    Ok(Some(FastqRecord {id, description, seq, qual}))
+  }
+}
+
+impl<R: BufRead> Iterator for FastqReader<R> {
+  type Item = RosalindResult<FastqRecord>;
+
+  fn next(&mut self) -> Option<RosalindResult<FastqRecord>> {
+    if self.done { return None; }
+
+    match self.read_record() {
+      Ok(Some(record)) => Some(Ok(record)),
+      Ok(None) => {
+        self.done = true;
+        None
+      },
+      Err(err) => {
+        self.done = true;
+        Some(Err(err))
+      }
+    }
+  }
+}
+
+/// This structure writes FASTQ records into any writer
+///
+/// ## Examples
+/// ```
+/// use rosalind::fastq::*;
+///
+/// let mut writer = FastqWriter::new(Vec::new());
+/// writer.write_record(&FastqRecord::new("read_1", "lane 1", "ACGT", "!+5?")).unwrap();
+///
+/// let dataset = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(dataset, "@read_1 lane 1\nACGT\n+\n!+5?\n");
+/// ```
+pub struct FastqWriter<W: Write> {
+  writer: W,
+  line_number: usize,
+}
+
+impl<W: Write> FastqWriter<W> {
+  /// This function creates FASTQ writer
+  pub fn new(writer: W) -> FastqWriter<W> {
+    FastqWriter { writer, line_number: 0 }
+  }
+
+  /// This function writes all four lines of the record, sequence and quality
+  /// string must have equal length
+  pub fn write_record(&mut self, record: &FastqRecord) -> RosalindResult<()> {
+    if record.seq.len() != record.qual.len() {
+      return Err(FastqQualityLengthError(record.id.clone(), self.line_number + 1));
+    }
+    self.line_number += 4;
+
+    try!(write!(self.writer, "{}{}", FASTQ_LABEL_SYMBOL, record.id));
+    if !record.description.is_empty() {
+      try!(write!(self.writer, " {}", record.description));
+    }
+    try!(write!(self.writer, "\n{}\n{}\n{}\n", record.seq, FASTQ_SEPARATOR_SYMBOL, record.qual));
+
+    Ok(())
+  }
+
+  /// This function flushes underlying writer
+  pub fn flush(&mut self) -> RosalindResult<()> {
+    try!(self.writer.flush());
+    Ok(())
+  }
+
+  /// This function returns underlying writer
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::RosalindError::{FastqParseError, FastqQualityLengthError, InvalidQualityScore};
+
+  fn read_records(dataset: &str) -> Result<Vec<FastqRecord>, ::RosalindError> {
+    FastqReader::new(dataset.as_bytes()).collect()
+  }
+
+  #[test]
+  fn it_should_decode_phred33_quality() {
+    assert_eq!(decode_quality("!+5?I~", QualityEncoding::Phred33).unwrap(), vec![0, 10, 20, 30, 40, 93]);
+  }
+
+  #[test]
+  fn it_should_decode_phred64_quality() {
+    assert_eq!(decode_quality("@JT^h~", QualityEncoding::Phred64).unwrap(), vec![0, 10, 20, 30, 40, 62]);
+  }
+
+  #[test]
+  fn it_should_return_error_when_quality_is_out_of_range() {
+    assert_eq!(decode_quality("I ", QualityEncoding::Phred33).unwrap_err(), InvalidQualityScore(' '));
+    assert_eq!(decode_quality("?", QualityEncoding::Phred64).unwrap_err(), InvalidQualityScore('?'));
+    assert_eq!(encode_quality(&[63], QualityEncoding::Phred64).unwrap_err(), InvalidQualityScore('\x7f'));
+  }
+
+  #[test]
+  fn it_should_encode_quality() {
+    let scores = vec![0, 10, 20, 30, 40];
+    let qual = encode_quality(&scores, QualityEncoding::Phred33).unwrap();
+    assert_eq!(decode_quality(&qual, QualityEncoding::Phred33).unwrap(), scores);
+  }
+
+  #[test]
+  fn it_should_read_fastq_records() {
+    let dataset = "@read_1 lane 1
+      AGCTATAG
+      +
+      IIIIIIII
+
+      @read_2
+      GGCC
+      +read_2
+      @@II";
+
+    assert_eq!(read_records(dataset).unwrap(), vec![
+      FastqRecord::new("read_1", "lane 1", "AGCTATAG", "IIIIIIII"),
+      FastqRecord::new("read_2", "", "GGCC", "@@II"),
+    ]);
+  }
+
+  #[test]
+  fn it_should_return_error_when_label_is_missing() {
+    assert_eq!(read_records("AGCT\n+\nIIII\n").unwrap_err(), FastqParseError(1));
+  }
+
+  #[test]
+  fn it_should_return_error_when_separator_is_missing() {
+    assert_eq!(read_records("@read_1\nAGCT\nIIII\n@read_2\n").unwrap_err(), FastqParseError(3));
+  }
+
+  #[test]
+  fn it_should_return_error_when_record_is_truncated() {
+    assert_eq!(read_records("@read_1\nAGCT\n+\nIIII\n@read_2\nAGCT\n").unwrap_err(), FastqParseError(7));
+  }
+
+  #[test]
+  fn it_should_return_error_when_lengths_differ() {
+    assert_eq!(read_records("@read_1\nAGCT\n+\nIIII\n@read_2\nAGCT\n+\nIII\n").unwrap_err(),
+      FastqQualityLengthError("read_2".to_string(), 5));
+  }
+
+  #[test]
+  fn it_should_write_fastq_records() {
+    let mut writer = FastqWriter::new(Vec::new());
+    writer.write_record(&FastqRecord::new("read_1", "lane 1", "AGCT", "II!!")).unwrap();
+    writer.write_record(&FastqRecord::new("read_2", "", "GG", "@@")).unwrap();
+
+    let dataset = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(dataset, "@read_1 lane 1\nAGCT\n+\nII!!\n@read_2\nGG\n+\n@@\n");
+  }
+
+  #[test]
+  fn it_should_not_write_record_when_lengths_differ() {
+    let mut writer = FastqWriter::new(Vec::new());
+    writer.write_record(&FastqRecord::new("read_1", "", "AGCT", "IIII")).unwrap();
+    assert_eq!(writer.write_record(&FastqRecord::new("read_2", "", "AGCT", "II")).unwrap_err(),
+      FastqQualityLengthError("read_2".to_string(), 5));
+  }
+
+  #[test]
+  fn it_should_round_trip_fastq_records() {
+    let records = vec![
+      FastqRecord::new("read_1", "lane 1", "AGCTATAG", "IIIII!!!"),
+      FastqRecord::new("read_2", "", "GGCC", "@+II"),
+    ];
+
+    let mut writer = FastqWriter::new(Vec::new());
+    for record in records.iter() {
+      writer.write_record(record).unwrap();
+    }
+
+    let dataset = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(read_records(&dataset).unwrap(), records);
+  }
+}
@@ -70,7 +70,7 @@ pub fn recurrence_relation_with_stop(n: usize, m: usize) -> RosalindResult<BigUi
       d.push(f);
     } else if i == m {
       {
-        f = d.get(i - 2).unwrap() + d.get(i - 1).unwrap() - &(One::one());
+        f = d.get(i - 2).unwrap() + d.get(i - 1).unwrap() - &BigUint::one();
       }
       d.push(f);
     } else {
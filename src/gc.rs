@@ -2,7 +2,8 @@
 
 use std::fmt;
 use RosalindResult;
-use constants::FASTA_LABEL_SYMBOL;
+use fasta::{FastaReader, FastaRecord};
+use seq::DnaSeq;
 
 /// This structure contains info about the string with the highest GC content
 #[allow(non_snake_case)]
@@ -22,25 +23,16 @@ impl fmt::Display for GCcontent {
 ///
 /// ## Examples
 /// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
 /// use rosalind::gc::*;
 ///
 /// assert_eq!(gc_content("").unwrap(), 0f32);
 /// assert_eq!(gc_content("AGCTATAG").unwrap(), 37.5f32);
+/// assert_eq!(gc_content("AGctatag").unwrap(), 37.5f32);
+/// assert_eq!(gc_content("AGCZ").unwrap_err(), UnknownNucleotide('Z'));
 /// ```
 pub fn gc_content(dna: &str) -> RosalindResult<f32> {
-  let dna_len = dna.len();
-  if dna_len == 0 { return Ok(0f32); }
-
-  let gc_counter: f32 = dna.chars().fold(0f32, |mut counter, nucleotide| {
-    match nucleotide {
-      'G' | 'C' => counter += 1f32,
-      _ => {},
-    }
-    counter
-  });
-
-  let gc: f32 = gc_counter * 100f32 / (dna_len as f32);
-  Ok(gc)
+  Ok(try!(DnaSeq::new(dna)).gc_content())
 }
 
 /// This function calculates best gc content in the given dataset and
@@ -63,28 +55,36 @@ pub fn gc_content(dna: &str) -> RosalindResult<f32> {
 /// assert_eq!(best_gc_content_in_dataset(dataset).unwrap(),
 ///   GCcontent {string_id: "Rosalind_0808".to_string(), gc_content: 60.919540f32});
 /// ```
-// TODO: refactor - use utils:parse_fasta_dataset
 pub fn best_gc_content_in_dataset(dataset: &str) -> RosalindResult<GCcontent> {
+  best_gc_content_in_records(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function calculates gc content of every given FASTA record and returns
+/// ID of the record with the highest GC content and GC content
+///
+/// ## Examples
+/// ```
+/// use rosalind::gc::*;
+/// use rosalind::fasta::FastaReader;
+///
+/// let dataset = ">Rosalind_1\nAGCTATAG\n>Rosalind_2\nGGCCTATA\n";
+/// let records = FastaReader::new(dataset.as_bytes());
+///
+/// assert_eq!(best_gc_content_in_records(records).unwrap(),
+///   GCcontent {string_id: "Rosalind_2".to_string(), gc_content: 50f32});
+/// ```
+pub fn best_gc_content_in_records<I>(records: I) -> RosalindResult<GCcontent>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
   let mut best_gc_label = "".to_string();
   let mut best_gc_content = 0f32;
-  let mut current_gc_content: f32;
-  let mut dna_string = "".to_string();
-
-  for mut dataset_line in dataset.lines().rev() {
-    dataset_line = dataset_line.trim();
-    let first_symbol = dataset_line.chars().nth(0).unwrap();
-
-    if first_symbol == FASTA_LABEL_SYMBOL {
-      current_gc_content = gc_content(&dna_string).unwrap();
 
-      if current_gc_content > best_gc_content {
-        best_gc_label = dataset_line[1..].to_string();
-        best_gc_content = current_gc_content;
-      }
+  for record in records {
+    let record = try!(record);
+    let current_gc_content = try!(gc_content(&record.seq));
 
-      dna_string = "".to_string();
-    } else {
-      dna_string = dna_string + dataset_line;
+    if current_gc_content > best_gc_content {
+      best_gc_label = record.id;
+      best_gc_content = current_gc_content;
     }
   }
 
@@ -95,7 +95,10 @@ pub fn best_gc_content_in_dataset(dataset: &str) -> RosalindResult<GCcontent> {
 mod tests {
   use super::gc_content;
   use super::best_gc_content_in_dataset;
+  use super::best_gc_content_in_records;
   use super::GCcontent;
+  use fasta::FastaReader;
+  use super::super::RosalindError::UnknownNucleotide;
 
   #[test]
   fn it_should_return_0_for_empty_dna_string() {
@@ -107,6 +110,16 @@ mod tests {
     assert_eq!(gc_content("AGCTATAG").unwrap(), 37.5f32);
   }
 
+  #[test]
+  fn it_should_calculate_gc_content_of_soft_masked_dna() {
+    assert_eq!(gc_content("agctATAG").unwrap(), 37.5f32);
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(gc_content("AGCZ").unwrap_err(), UnknownNucleotide('Z'));
+  }
+
   #[test]
   fn it_should_calculate_best_gc_content() {
     let dataset = ">Rosalind_6404
@@ -123,6 +136,22 @@ mod tests {
       GCcontent {string_id: "Rosalind_0808".to_string(), gc_content: 60.919540f32});
   }
 
+  #[test]
+  fn it_should_calculate_best_gc_content_in_records() {
+    let dataset = ">Rosalind_1\nAGCTATAG\n\n>Rosalind_2\nGGCCTATA\n>Rosalind_3\nATAT\n";
+    let records = FastaReader::new(dataset.as_bytes());
+
+    assert_eq!(best_gc_content_in_records(records).unwrap(),
+      GCcontent {string_id: "Rosalind_2".to_string(), gc_content: 50f32});
+  }
+
+  #[test]
+  fn it_should_skip_blank_lines_in_dataset() {
+    let dataset = "\n>Rosalind_1\nAGCTATAG\n\n>Rosalind_2\n\nGGCCTATA\n\n";
+    assert_eq!(best_gc_content_in_dataset(dataset).unwrap(),
+      GCcontent {string_id: "Rosalind_2".to_string(), gc_content: 50f32});
+  }
+
   #[test]
   fn it_should_format_gc_content() {
     let gc_content = GCcontent {string_id: "Rosalind_0808".to_string(), gc_content: 123.45f32};
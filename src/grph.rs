@@ -0,0 +1,149 @@
+//! Module for `Overlap Graphs`
+
+use std::collections::HashMap;
+use RosalindResult;
+use RosalindError::InvalidInputParameters;
+use fasta::{FastaReader, FastaRecord};
+
+/// This function builds overlap graph `O_k` of strings in FASTA dataset and
+/// returns its edges as pairs of record IDs
+///
+/// ## Examples
+/// ```
+/// use rosalind::grph::*;
+///
+/// let dataset = ">Rosalind_0498
+///   AAATAAA
+///   >Rosalind_2391
+///   AAATTTT
+///   >Rosalind_2323
+///   TTTTCCC
+///   >Rosalind_0442
+///   AAATCCC
+///   >Rosalind_5013
+///   GGGTGGG";
+///
+/// let edges = overlap_graph_in_dataset(dataset, 3).unwrap();
+/// assert_eq!(edges, vec![
+///   ("Rosalind_0498".to_string(), "Rosalind_2391".to_string()),
+///   ("Rosalind_0498".to_string(), "Rosalind_0442".to_string()),
+///   ("Rosalind_2391".to_string(), "Rosalind_2323".to_string()),
+/// ]);
+/// ```
+pub fn overlap_graph_in_dataset(dataset: &str, k: usize) -> RosalindResult<Vec<(String, String)>> {
+  overlap_graph(FastaReader::new(dataset.as_bytes()), k)
+}
+
+/// This function builds overlap graph `O_k` of FASTA records. There is an edge from `s`
+/// to `t` when the suffix of `s` of length `k` equals the prefix of `t` of length `k`
+/// and `s` and `t` are different records. Records are indexed by prefixes, so only
+/// overlapping pairs are compared. Edges are ordered by records `s` and then by
+/// records `t`.
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::grph::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![
+///   FastaRecord::new("s", "", "ACGTT"),
+///   FastaRecord::new("t", "", "GTTAC"),
+///   FastaRecord::new("u", "", "TTACG"),
+/// ];
+/// assert_eq!(overlap_graph(records.iter().cloned().map(Ok), 2).unwrap(), vec![
+///   ("s".to_string(), "u".to_string()),
+///   ("t".to_string(), "s".to_string()),
+/// ]);
+/// assert_eq!(overlap_graph(records.into_iter().map(Ok), 0).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn overlap_graph<I>(records: I, k: usize) -> RosalindResult<Vec<(String, String)>>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  if k == 0 { return Err(InvalidInputParameters); }
+
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+
+  let mut prefixes: HashMap<&str, Vec<usize>> = HashMap::new();
+  for (i, record) in records.iter().enumerate() {
+    if let Some(prefix) = record.seq.get(..k) {
+      prefixes.entry(prefix).or_default().push(i);
+    }
+  }
+
+  let mut edges = Vec::new();
+  for (i, record) in records.iter().enumerate() {
+    let suffix = match record.seq.len().checked_sub(k).and_then(|start| record.seq.get(start..)) {
+      Some(suffix) => suffix,
+      None => continue,
+    };
+
+    if let Some(targets) = prefixes.get(suffix) {
+      for &j in targets.iter().filter(|&&j| j != i) {
+        edges.push((record.id.clone(), records[j].id.clone()));
+      }
+    }
+  }
+
+  Ok(edges)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use fasta::FastaRecord;
+  use super::super::RosalindError::{InvalidInputParameters, FastaMissingLabel};
+
+  const DATASET: &'static str = ">Rosalind_0498
+AAATAAA
+>Rosalind_2391
+AAATTTT
+>Rosalind_2323
+TTTTCCC
+>Rosalind_0442
+AAATCCC
+>Rosalind_5013
+GGGTGGG
+";
+
+  fn edge(s: &str, t: &str) -> (String, String) {
+    (s.to_string(), t.to_string())
+  }
+
+  #[test]
+  fn it_should_build_overlap_graph() {
+    assert_eq!(overlap_graph_in_dataset(DATASET, 3).unwrap(), vec![
+      edge("Rosalind_0498", "Rosalind_2391"),
+      edge("Rosalind_0498", "Rosalind_0442"),
+      edge("Rosalind_2391", "Rosalind_2323"),
+    ]);
+  }
+
+  #[test]
+  fn it_should_not_connect_record_to_itself() {
+    let records = vec![Ok(FastaRecord::new("s", "", "AAAA")), Ok(FastaRecord::new("t", "", "AAAC"))];
+    assert_eq!(overlap_graph(records, 3).unwrap(), vec![edge("s", "t")]);
+  }
+
+  #[test]
+  fn it_should_connect_records_with_equal_strings() {
+    let records = vec![Ok(FastaRecord::new("s", "", "ACAC")), Ok(FastaRecord::new("t", "", "ACAC"))];
+    assert_eq!(overlap_graph(records, 2).unwrap(), vec![edge("s", "t"), edge("t", "s")]);
+  }
+
+  #[test]
+  fn it_should_skip_records_shorter_than_k() {
+    let records = vec![Ok(FastaRecord::new("s", "", "AC")), Ok(FastaRecord::new("t", "", "ACGT"))];
+    assert_eq!(overlap_graph(records, 3).unwrap(), vec![]);
+  }
+
+  #[test]
+  fn it_should_return_error_when_k_is_zero() {
+    assert_eq!(overlap_graph_in_dataset(DATASET, 0).unwrap_err(), InvalidInputParameters);
+  }
+
+  #[test]
+  fn it_should_return_error_when_record_is_malformed() {
+    let records = vec![Ok(FastaRecord::new("s", "", "ACGT")), Err(FastaMissingLabel(3))];
+    assert_eq!(overlap_graph(records, 3).unwrap_err(), FastaMissingLabel(3));
+  }
+}
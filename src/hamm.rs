@@ -2,6 +2,7 @@
 
 use RosalindResult;
 use RosalindError::HammingStringsLengthError;
+use packed::PackedDna;
 
 /// This function calculates Hamming distance between `s` and `t`
 ///
@@ -29,9 +30,26 @@ pub fn hamming_distance(s: &str, t: &str) -> RosalindResult<u16> {
   Ok(distance)
 }
 
+/// This function calculates Hamming distance between packed DNA strings `s` and `t`
+/// comparing 32 nucleotides at once
+///
+/// # Examples
+/// ```
+/// use rosalind::packed::PackedDna;
+/// use rosalind::hamm::*;
+///
+/// let s = PackedDna::new("GAGCCTACTAACGGGAT").unwrap();
+/// let t = PackedDna::new("CATCGTAATGACGGCCT").unwrap();
+/// assert_eq!(hamming_distance_packed(&s, &t).unwrap(), 7);
+/// ```
+pub fn hamming_distance_packed(s: &PackedDna, t: &PackedDna) -> RosalindResult<usize> {
+  s.hamming_distance(t)
+}
+
 #[cfg(test)]
 mod tests {
-  use super::hamming_distance;
+  use super::{hamming_distance, hamming_distance_packed};
+  use packed::PackedDna;
   use super::super::RosalindError::HammingStringsLengthError;
 
   #[test]
@@ -45,4 +63,12 @@ mod tests {
   fn it_should_return_error_when_strings_have_different_length() {
     assert_eq!(hamming_distance("G", "").unwrap_err(), HammingStringsLengthError);
   }
+
+  #[test]
+  fn it_should_return_hamming_distance_of_packed_dna() {
+    let s = PackedDna::new("GAGCCTACTAACGGGATN").unwrap();
+    let t = PackedDna::new("CATCGTAATGACGGCCTA").unwrap();
+    assert_eq!(hamming_distance_packed(&s, &t).unwrap(), 8);
+    assert_eq!(hamming_distance_packed(&s, &PackedDna::new("G").unwrap()).unwrap_err(), HammingStringsLengthError);
+  }
 }
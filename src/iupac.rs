@@ -0,0 +1,162 @@
+//! Module for IUPAC nucleotide ambiguity codes
+
+use RosalindResult;
+use RosalindError::UnknownNucleotide;
+
+const A: u8 = 0b0001;
+const C: u8 = 0b0010;
+const G: u8 = 0b0100;
+const T: u8 = 0b1000;
+
+/// This function returns set of nucleotides denoted by IUPAC code as a bit mask,
+/// where `A`, `C`, `G` and `T` (or `U`) are represented by bits from lowest to highest.
+/// Codes are case-insensitive.
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::iupac::*;
+///
+/// assert_eq!(nucleotide_mask('A').unwrap(), 0b0001);
+/// assert_eq!(nucleotide_mask('R').unwrap(), 0b0101);
+/// assert_eq!(nucleotide_mask('N').unwrap(), 0b1111);
+/// assert_eq!(nucleotide_mask('Z').unwrap_err(), UnknownNucleotide('Z'));
+/// ```
+pub fn nucleotide_mask(nucleotide: char) -> RosalindResult<u8> {
+  match nucleotide.to_ascii_uppercase() {
+    'A' => Ok(A),
+    'C' => Ok(C),
+    'G' => Ok(G),
+    'T' | 'U' => Ok(T),
+    'R' => Ok(A | G),
+    'Y' => Ok(C | T),
+    'S' => Ok(C | G),
+    'W' => Ok(A | T),
+    'K' => Ok(G | T),
+    'M' => Ok(A | C),
+    'B' => Ok(C | G | T),
+    'D' => Ok(A | G | T),
+    'H' => Ok(A | C | T),
+    'V' => Ok(A | C | G),
+    'N' => Ok(A | C | G | T),
+    _ => Err(UnknownNucleotide(nucleotide)),
+  }
+}
+
+/// This function checks whether IUPAC code denotes more than one nucleotide
+///
+/// ## Examples
+/// ```
+/// use rosalind::iupac::*;
+///
+/// assert_eq!(is_ambiguous('N').unwrap(), true);
+/// assert_eq!(is_ambiguous('T').unwrap(), false);
+/// ```
+pub fn is_ambiguous(nucleotide: char) -> RosalindResult<bool> {
+  Ok(try!(nucleotide_mask(nucleotide)).count_ones() > 1)
+}
+
+/// This function returns complement of IUPAC DNA code, case of the code is preserved
+///
+/// ## Examples
+/// ```
+/// use rosalind::iupac::*;
+///
+/// assert_eq!(complement('A').unwrap(), 'T');
+/// assert_eq!(complement('R').unwrap(), 'Y');
+/// assert_eq!(complement('B').unwrap(), 'V');
+/// assert_eq!(complement('N').unwrap(), 'N');
+/// assert_eq!(complement('g').unwrap(), 'c');
+/// ```
+pub fn complement(nucleotide: char) -> RosalindResult<char> {
+  let complement = match nucleotide.to_ascii_uppercase() {
+    'A' => 'T',
+    'C' => 'G',
+    'G' => 'C',
+    'T' => 'A',
+    'R' => 'Y',
+    'Y' => 'R',
+    'S' => 'S',
+    'W' => 'W',
+    'K' => 'M',
+    'M' => 'K',
+    'B' => 'V',
+    'V' => 'B',
+    'D' => 'H',
+    'H' => 'D',
+    'N' => 'N',
+    _ => return Err(UnknownNucleotide(nucleotide)),
+  };
+
+  if nucleotide.is_ascii_lowercase() { Ok(complement.to_ascii_lowercase()) } else { Ok(complement) }
+}
+
+/// This function checks whether every nucleotide denoted by `nucleotide` code is
+/// also denoted by `code`, so degenerate code matches any of its nucleotides
+///
+/// ## Examples
+/// ```
+/// use rosalind::iupac::*;
+///
+/// assert_eq!(matches('N', 'G').unwrap(), true);
+/// assert_eq!(matches('R', 'A').unwrap(), true);
+/// assert_eq!(matches('R', 'C').unwrap(), false);
+/// assert_eq!(matches('A', 'N').unwrap(), false);
+/// ```
+pub fn matches(code: char, nucleotide: char) -> RosalindResult<bool> {
+  let (code_mask, nucleotide_mask) = (try!(nucleotide_mask(code)), try!(nucleotide_mask(nucleotide)));
+  Ok(nucleotide_mask & !code_mask == 0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::RosalindError::UnknownNucleotide;
+
+  const IUPAC_CODES: &'static str = "ACGTRYSWKMBDHVN";
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(nucleotide_mask('Z').unwrap_err(), UnknownNucleotide('Z'));
+    assert_eq!(complement('U').unwrap_err(), UnknownNucleotide('U'));
+  }
+
+  #[test]
+  fn it_should_complement_ambiguity_codes() {
+    let complements: String = IUPAC_CODES.chars().map(|c| complement(c).unwrap()).collect();
+    assert_eq!(complements, "TGCAYRSWMKVHDBN");
+  }
+
+  #[test]
+  fn it_should_complement_lowercase_ambiguity_codes() {
+    let complements: String = IUPAC_CODES.to_lowercase().chars().map(|c| complement(c).unwrap()).collect();
+    assert_eq!(complements, "tgcayrswmkvhdbn");
+    assert_eq!(nucleotide_mask('r').unwrap(), nucleotide_mask('R').unwrap());
+    assert_eq!(complement('u').unwrap_err(), UnknownNucleotide('u'));
+  }
+
+  #[test]
+  fn it_should_complement_nucleotide_sets() {
+    for code in IUPAC_CODES.chars() {
+      let mask = nucleotide_mask(code).unwrap();
+      let complement_mask = nucleotide_mask(complement(code).unwrap()).unwrap();
+      assert_eq!(complement_mask, mask.reverse_bits() >> 4);
+    }
+  }
+
+  #[test]
+  fn it_should_detect_ambiguity_codes() {
+    let ambiguous: Vec<bool> = IUPAC_CODES.chars().map(|c| is_ambiguous(c).unwrap()).collect();
+    assert_eq!(&ambiguous[..4], &[false; 4]);
+    assert_eq!(&ambiguous[4..], &[true; 11]);
+  }
+
+  #[test]
+  fn it_should_match_degenerate_code() {
+    assert_eq!(matches('S', 'C').unwrap(), true);
+    assert_eq!(matches('S', 'A').unwrap(), false);
+    assert_eq!(matches('N', 'R').unwrap(), true);
+    assert_eq!(matches('R', 'N').unwrap(), false);
+    assert_eq!(matches('T', 'U').unwrap(), true);
+  }
+}
@@ -0,0 +1,225 @@
+//! Module for `Finding a Shared Motif`
+
+use std::collections::VecDeque;
+use RosalindResult;
+use RosalindError::InvalidInputParameters;
+use fasta::{FastaReader, FastaRecord};
+
+// builds suffix array by prefix doubling
+fn suffix_array(text: &[u32]) -> Vec<usize> {
+  let n = text.len();
+  let mut sa: Vec<usize> = (0..n).collect();
+  if n == 0 { return sa; }
+  let mut rank: Vec<usize> = text.iter().map(|&symbol| symbol as usize).collect();
+  let mut next_rank = vec![0; n];
+
+  let mut k = 1;
+  loop {
+    {
+      let key = |i: usize| (rank[i], if i + k < n { rank[i + k] + 1 } else { 0 });
+      sa.sort_by_key(|&i| key(i));
+      next_rank[sa[0]] = 0;
+      for i in 1..n {
+        next_rank[sa[i]] = next_rank[sa[i - 1]] + if key(sa[i - 1]) < key(sa[i]) { 1 } else { 0 };
+      }
+    }
+    ::std::mem::swap(&mut rank, &mut next_rank);
+    if rank[sa[n - 1]] == n - 1 { break; }
+    k *= 2;
+  }
+
+  sa
+}
+
+// builds array of longest common prefixes of adjacent suffixes (Kasai algorithm),
+// `lcp[i]` is the longest common prefix of suffixes `sa[i - 1]` and `sa[i]`
+fn lcp_array(text: &[u32], sa: &[usize]) -> Vec<usize> {
+  let n = text.len();
+  let mut rank = vec![0; n];
+  for (i, &suffix) in sa.iter().enumerate() { rank[suffix] = i; }
+
+  let mut lcp = vec![0; n];
+  let mut h = 0;
+  for suffix in 0..n {
+    if rank[suffix] == 0 { h = 0; continue; }
+    let previous = sa[rank[suffix] - 1];
+    while suffix + h < n && previous + h < n && text[suffix + h] == text[previous + h] { h += 1; }
+    lcp[rank[suffix]] = h;
+    h = h.saturating_sub(1);
+  }
+  lcp
+}
+
+/// This function finds the longest substring shared by all strings using generalized
+/// suffix array, symbols are case-insensitive and the motif is returned in uppercase.
+/// If there are several longest motifs, the lexicographically smallest one is returned.
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::lcsm::*;
+///
+/// assert_eq!(shared_motif(&["GATTACA", "TAGACCA", "ATACA"]).unwrap(), "AC");
+/// assert_eq!(shared_motif(&["AAAA", "CCCC"]).unwrap(), "");
+/// assert_eq!(shared_motif::<&str>(&[]).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn shared_motif<S: AsRef<str>>(strings: &[S]) -> RosalindResult<String> {
+  if strings.is_empty() { return Err(InvalidInputParameters); }
+  if strings.len() == 1 { return Ok(strings[0].as_ref().to_ascii_uppercase()); }
+
+  // strings are joined with distinct separators which are greater than any symbol,
+  // so suffixes of separators are placed at the end of suffix array
+  let mut text: Vec<u32> = Vec::new();
+  let mut owners: Vec<usize> = Vec::new();
+  for (i, string) in strings.iter().enumerate() {
+    text.extend(string.as_ref().bytes().map(|symbol| symbol.to_ascii_uppercase() as u32));
+    text.push(256 + i as u32);
+    owners.extend(::std::iter::repeat(i).take(string.as_ref().len() + 1));
+  }
+
+  let sa = suffix_array(&text);
+  let lcp = lcp_array(&text, &sa);
+  let suffixes = text.len() - strings.len();
+
+  // sliding window over suffix array covering suffixes of all strings,
+  // minimum of longest common prefixes in the window is kept in monotonic queue
+  let (mut best_len, mut best_start) = (0, 0);
+  let mut counts = vec![0; strings.len()];
+  let mut covered = 0;
+  let mut window_lcp: VecDeque<usize> = VecDeque::new();
+  let mut left = 0;
+
+  for right in 0..suffixes {
+    let owner = owners[sa[right]];
+    if counts[owner] == 0 { covered += 1; }
+    counts[owner] += 1;
+
+    if right > left {
+      while window_lcp.back().map_or(false, |&i| lcp[i] >= lcp[right]) { window_lcp.pop_back(); }
+      window_lcp.push_back(right);
+    }
+
+    while covered == strings.len() {
+      let len = window_lcp.front().map_or(0, |&i| lcp[i]);
+      if len > best_len { best_len = len; best_start = sa[left]; }
+
+      let owner = owners[sa[left]];
+      counts[owner] -= 1;
+      if counts[owner] == 0 { covered -= 1; }
+      left += 1;
+      while window_lcp.front().map_or(false, |&i| i <= left) { window_lcp.pop_front(); }
+    }
+  }
+
+  Ok(text[best_start..best_start + best_len].iter().map(|&symbol| symbol as u8 as char).collect())
+}
+
+/// This function finds the longest motif shared by all strings in FASTA dataset
+///
+/// ## Examples
+/// ```
+/// use rosalind::lcsm::*;
+///
+/// let dataset = ">Rosalind_1
+///   GATTACA
+///   >Rosalind_2
+///   TAGACCA
+///   >Rosalind_3
+///   ATACA";
+///
+/// assert_eq!(shared_motif_in_dataset(dataset).unwrap(), "AC");
+/// ```
+pub fn shared_motif_in_dataset(dataset: &str) -> RosalindResult<String> {
+  shared_motif_in_records(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function finds the longest motif shared by sequences of all given FASTA records
+///
+/// ## Examples
+/// ```
+/// use rosalind::lcsm::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![Ok(FastaRecord::new("1", "", "GATTACA")), Ok(FastaRecord::new("2", "", "TTACT"))];
+/// assert_eq!(shared_motif_in_records(records).unwrap(), "TTAC");
+/// ```
+pub fn shared_motif_in_records<I>(records: I) -> RosalindResult<String>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+  let strings: Vec<&str> = records.iter().map(|record| &*record.seq).collect();
+  shared_motif(&strings)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::{suffix_array, lcp_array};
+  use utils::random_string;
+  use super::super::RosalindError::InvalidInputParameters;
+
+  fn brute_force_shared_motif(strings: &[&str]) -> usize {
+    let first = strings[0];
+    let mut best = 0;
+    for i in 0..first.len() {
+      for j in (i + 1)..(first.len() + 1) {
+        if j - i > best && strings.iter().all(|s| s.contains(&first[i..j])) { best = j - i; }
+      }
+    }
+    best
+  }
+
+  #[test]
+  fn it_should_build_suffix_array() {
+    let text: Vec<u32> = "BANANA".bytes().map(|b| b as u32).collect();
+    let sa = suffix_array(&text);
+    assert_eq!(sa, vec![5, 3, 1, 0, 4, 2]);
+    assert_eq!(lcp_array(&text, &sa), vec![0, 1, 3, 0, 0, 2]);
+  }
+
+  #[test]
+  fn it_should_find_shared_motif() {
+    assert_eq!(shared_motif(&["GATTACA", "TAGACCA", "ATACA"]).unwrap(), "AC");
+  }
+
+  #[test]
+  fn it_should_find_shared_motif_case_insensitive() {
+    assert_eq!(shared_motif(&["ggATTACA", "ccattaca"]).unwrap(), "ATTACA");
+  }
+
+  #[test]
+  fn it_should_find_shared_motif_of_single_string() {
+    assert_eq!(shared_motif(&["GATTACA"]).unwrap(), "GATTACA");
+    assert_eq!(shared_motif(&["GATTACA", "GATTACA"]).unwrap(), "GATTACA");
+    assert_eq!(shared_motif(&["GATTACA", ""]).unwrap(), "");
+  }
+
+  #[test]
+  fn it_should_find_shared_motif_as_brute_force() {
+    let strings = vec![
+      "ACGTTGCATGCATGCAAGTCCGATTAGCATGCA",
+      "TTGCATGCATGCATTCCGATTAGCAACGTCA",
+      "GGCATGCATTCCGATTAGCATCCGATTAGCAT",
+      "CCGATTAGCATGCATGCATTTTGCATGC",
+    ];
+    let motif = shared_motif(&strings).unwrap();
+    assert_eq!(motif.len(), brute_force_shared_motif(&strings));
+    assert!(strings.iter().all(|s| s.contains(&*motif)));
+  }
+
+  #[test]
+  fn it_should_find_shared_motif_in_long_strings() {
+    let motif = "GATTACAGATTACACCGGTTAACCGGTTAA";
+    let mut seed: u32 = 42;
+    let strings: Vec<String> = (0..20).map(|i| {
+      let mut dna = random_string("ACGT", 2000, &mut seed);
+      dna.insert_str(i * 50, motif);
+      dna
+    }).collect();
+    assert!(shared_motif(&strings).unwrap().contains(motif));
+  }
+
+  #[test]
+  fn it_should_return_error_when_no_strings_given() {
+    assert_eq!(shared_motif_in_dataset("").unwrap_err(), InvalidInputParameters);
+  }
+}
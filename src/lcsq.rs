@@ -0,0 +1,181 @@
+//! Module for `Finding a Shared Spliced Motif`
+
+use RosalindResult;
+use RosalindError::InvalidInputParameters;
+use fasta::{FastaReader, FastaRecord};
+
+/// This structure contains longest common subsequence of two strings and its traceback,
+/// i.e. zero-based pairs of locations of its symbols in the first and the second string
+#[derive(PartialEq, Debug)]
+pub struct CommonSubsequence {
+  pub subsequence: String,
+  pub traceback: Vec<(usize, usize)>,
+}
+
+// returns lengths of longest common subsequences of `s` and every prefix of `t`
+fn lcs_lengths<'a, I, J>(s: I, t: J) -> Vec<usize>
+  where I: Iterator<Item = &'a u8>, J: Iterator<Item = &'a u8> + Clone {
+  let t_len = t.clone().count();
+  let mut row = vec![0; t_len + 1];
+  for s_symbol in s {
+    let mut diagonal = 0;
+    for (j, t_symbol) in t.clone().enumerate() {
+      let up = row[j + 1];
+      row[j + 1] = if s_symbol == t_symbol { diagonal + 1 } else { up.max(row[j]) };
+      diagonal = up;
+    }
+  }
+  row
+}
+
+// Hirschberg algorithm, appends traceback of `s` and `t` shifted by offsets
+fn hirschberg(s: &[u8], t: &[u8], s_offset: usize, t_offset: usize, traceback: &mut Vec<(usize, usize)>) {
+  if s.is_empty() || t.is_empty() { return; }
+  if s.len() == 1 {
+    if let Some(j) = t.iter().position(|&symbol| symbol == s[0]) {
+      traceback.push((s_offset, t_offset + j));
+    }
+    return;
+  }
+
+  let middle = s.len() / 2;
+  let forward = lcs_lengths(s[..middle].iter(), t.iter());
+  let backward = lcs_lengths(s[middle..].iter().rev(), t.iter().rev());
+
+  let mut split = 0;
+  for k in 1..(t.len() + 1) {
+    if forward[k] + backward[t.len() - k] > forward[split] + backward[t.len() - split] { split = k; }
+  }
+
+  hirschberg(&s[..middle], &t[..split], s_offset, t_offset, traceback);
+  hirschberg(&s[middle..], &t[split..], s_offset + middle, t_offset + split, traceback);
+}
+
+/// This function finds longest common subsequence of strings `s` and `t` in linear space
+/// using Hirschberg algorithm. If there are several longest common subsequences,
+/// any of them is returned.
+///
+/// ## Examples
+/// ```
+/// use rosalind::lcsq::*;
+///
+/// let lcs = longest_common_subsequence("AACCTTGG", "ACACTGTGA");
+/// assert_eq!(lcs.subsequence.len(), 6);
+/// assert_eq!(longest_common_subsequence("GATTACA", "TTC").traceback, vec![(2, 0), (3, 1), (5, 2)]);
+/// ```
+pub fn longest_common_subsequence(s: &str, t: &str) -> CommonSubsequence {
+  let (s_bytes, t_bytes) = (s.as_bytes(), t.as_bytes());
+  let mut traceback = Vec::new();
+  hirschberg(s_bytes, t_bytes, 0, 0, &mut traceback);
+
+  let subsequence = traceback.iter().map(|&(i, _)| s_bytes[i] as char).collect();
+  CommonSubsequence {subsequence, traceback}
+}
+
+/// This function finds longest common subsequence of two strings in FASTA dataset
+///
+/// ## Examples
+/// ```
+/// use rosalind::lcsq::*;
+///
+/// let dataset = ">Rosalind_23
+///   AACCTTGG
+///   >Rosalind_64
+///   ACACTGTGA";
+///
+/// assert_eq!(longest_common_subsequence_in_dataset(dataset).unwrap().subsequence.len(), 6);
+/// ```
+pub fn longest_common_subsequence_in_dataset(dataset: &str) -> RosalindResult<CommonSubsequence> {
+  longest_common_subsequence_in_records(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function finds longest common subsequence of sequences of two FASTA records,
+/// more or fewer records are an error
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::lcsq::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![Ok(FastaRecord::new("s", "", "GATTACA")), Ok(FastaRecord::new("t", "", "GCA"))];
+/// assert_eq!(longest_common_subsequence_in_records(records).unwrap().subsequence, "GCA");
+/// assert_eq!(longest_common_subsequence_in_records(vec![]).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn longest_common_subsequence_in_records<I>(records: I) -> RosalindResult<CommonSubsequence>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+  if records.len() != 2 { return Err(InvalidInputParameters); }
+  Ok(longest_common_subsequence(&records[0].seq, &records[1].seq))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::lcs_lengths;
+  use utils::random_string;
+
+  // quadratic space dynamic programming
+  fn lcs_length(s: &str, t: &str) -> usize {
+    let (s, t) = (s.as_bytes(), t.as_bytes());
+    let mut table = vec![vec![0; t.len() + 1]; s.len() + 1];
+    for i in 0..s.len() {
+      for j in 0..t.len() {
+        table[i + 1][j + 1] = if s[i] == t[j] { table[i][j] + 1 } else { table[i][j + 1].max(table[i + 1][j]) };
+      }
+    }
+    table[s.len()][t.len()]
+  }
+
+  fn assert_common_subsequence(s: &str, t: &str, lcs: &CommonSubsequence) {
+    assert_eq!(lcs.subsequence.len(), lcs_length(s, t));
+    assert_eq!(lcs.traceback.len(), lcs.subsequence.len());
+    for (k, &(i, j)) in lcs.traceback.iter().enumerate() {
+      assert_eq!(s.as_bytes()[i], lcs.subsequence.as_bytes()[k]);
+      assert_eq!(t.as_bytes()[j], lcs.subsequence.as_bytes()[k]);
+      if k > 0 { assert!(i > lcs.traceback[k - 1].0 && j > lcs.traceback[k - 1].1); }
+    }
+  }
+
+  #[test]
+  fn it_should_return_lcs_lengths_row() {
+    assert_eq!(lcs_lengths("AC".as_bytes().iter(), "CAC".as_bytes().iter()), vec![0, 1, 1, 2]);
+  }
+
+  #[test]
+  fn it_should_find_longest_common_subsequence() {
+    let lcs = longest_common_subsequence("AACCTTGG", "ACACTGTGA");
+    assert_common_subsequence("AACCTTGG", "ACACTGTGA", &lcs);
+    assert_eq!(lcs.subsequence.len(), 6);
+  }
+
+  #[test]
+  fn it_should_find_empty_subsequence() {
+    let empty = CommonSubsequence {subsequence: "".to_string(), traceback: vec![]};
+    assert_eq!(longest_common_subsequence("AAAA", "CCCC"), empty);
+    assert_eq!(longest_common_subsequence("", "ACGT"), empty);
+    assert_eq!(longest_common_subsequence("ACGT", ""), empty);
+  }
+
+  #[test]
+  fn it_should_find_subsequence_equal_to_string() {
+    let lcs = longest_common_subsequence("GATTACA", "GGAATTTACCA");
+    assert_eq!(lcs.subsequence, "GATTACA");
+  }
+
+  #[test]
+  fn it_should_find_longest_common_subsequence_of_random_strings() {
+    let mut seed = 7;
+    for &(s_len, t_len) in [(1, 5), (17, 33), (64, 64), (100, 37)].iter() {
+      let (s, t) = (random_string("ACGT", s_len, &mut seed), random_string("ACGT", t_len, &mut seed));
+      assert_common_subsequence(&s, &t, &longest_common_subsequence(&s, &t));
+    }
+  }
+
+  #[test]
+  fn it_should_find_longest_common_subsequence_of_kbp_strings() {
+    let mut seed = 42;
+    let (s, t) = (random_string("ACGT", 1000, &mut seed), random_string("ACGT", 1000, &mut seed));
+    assert_common_subsequence(&s, &t, &longest_common_subsequence(&s, &t));
+  }
+}
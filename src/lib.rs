@@ -76,6 +76,36 @@
 //! assert_eq!(get_protein_mass("AB").unwrap_err(), UnknownAminoAcid('B'));
 //! ```
 //!
+//! # Protein Translation
+//! ## Examples
+//! ```
+//! use rosalind::RosalindError::UnknownGeneticCode;
+//! use rosalind::prot::*;
+//!
+//! let code = GeneticCode::new(11).unwrap();
+//! assert_eq!(code.name(), "Bacterial, Archaeal and Plant Plastid");
+//! assert_eq!(code.start_codons(), vec!["UUG", "CUG", "AUU", "AUC", "AUA", "AUG", "GUG"]);
+//! assert_eq!(translate_rna_into_protein_with_code("AUGUGGUGA", &code).unwrap(), "MW");
+//! assert_eq!(translate_rna_into_protein_with_code("AUGUGAUAA", &GeneticCode::new(2).unwrap()).unwrap(), "MW");
+//! assert_eq!(GeneticCode::new(7).unwrap_err(), UnknownGeneticCode(7));
+//!
+//! let dna = "ATGGCCATGGCGCCCAGAACTGAGATCAATAGTACCCGTATTAACGGGTGA";
+//! assert!(find_genetic_codes(dna, "MAMAPRTEINSTRING").unwrap().contains(&1));
+//! ```
+//!
+//! # Six-frame translation
+//! ## Examples
+//! ```
+//! use rosalind::prot::*;
+//!
+//! let code = GeneticCode::standard();
+//! assert_eq!(translate_frame("ATGGCCTAAGCNNNNAT", &code).unwrap(), "MA*AX");
+//!
+//! let frames = translate_six_frames("ATGGCCTAAGG", &code).unwrap();
+//! assert_eq!(frames[0], ReadingFrame {strand: Strand::Forward, offset: 0, protein: "MA*".to_string()});
+//! assert_eq!(frames[3], ReadingFrame {strand: Strand::Reverse, offset: 0, protein: "P*A".to_string()});
+//! ```
+//!
 //! # Counting Point Mutations
 //! ## Examples
 //! ```
@@ -150,6 +180,377 @@
 //! assert_eq!(consensus(prof).unwrap(), "ATGCAACT");
 //! ```
 //!
+//! # Open Reading Frames
+//! ## Examples
+//! ```
+//! use rosalind::orf::*;
+//! use rosalind::prot::Strand;
+//!
+//! let dna = "AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG";
+//! let proteins = orf_proteins(dna, &OrfOptions::default()).unwrap();
+//! assert_eq!(proteins, vec!["M", "MGMTPRLGLESLLE", "MTPRLGLESLLE", "MLLGSFRLIPKETLIQVAGSSPCNLS"]);
+//!
+//! let options = OrfOptions {min_length: 5, nested: false, ..OrfOptions::default()};
+//! let orfs = find_orfs(dna, &options).unwrap();
+//! assert_eq!(orfs[0], Orf {strand: Strand::Forward, location: 24..69, protein: "MGMTPRLGLESLLE".to_string()});
+//! assert_eq!(orfs[1].strand, Strand::Reverse);
+//! ```
+//!
+//! # RNA Splicing
+//! ## Examples
+//! ```
+//! use rosalind::splc::*;
+//!
+//! let dataset = ">Rosalind_10
+//!   ATGGTCTACATAGCTGACAAACAGCACGTAGCAATCGGTCGAATCTCGAGAGGCATATGGTCACATGATCGGTCGAGCGTGTTTCAAAGTTTGCGCCTAG
+//!   >Rosalind_12
+//!   ATCGGTCGAA
+//!   >Rosalind_15
+//!   ATCGGTCGAGCGTGT";
+//! assert_eq!(splice_and_translate_dataset(dataset).unwrap(), "MVYIADKQHVASREAYGHMFKVCA");
+//!
+//! assert_eq!(remove_introns("ATGCCCTTTGGGTAA", &["CCC", "GGG"]), "ATGTTTTAA");
+//! assert_eq!(splice_exons("ATGCCCTTTGGGTAA", &[0..3, 6..9, 12..15]).unwrap(), "ATGTTTTAA");
+//! ```
+//!
+//! # Locating Restriction Sites
+//! ## Examples
+//! ```
+//! use rosalind::revc::*;
+//! use rosalind::restriction::*;
+//!
+//! let palindromes = reverse_palindromes("TCAATGCATGCGGGTCTATATGCAT").unwrap();
+//! assert_eq!(palindromes[0], ReversePalindrome {position: 4, length: 6});
+//! assert_eq!(palindromes.len(), 8);
+//!
+//! let ecori = restriction_enzyme("EcoRI").unwrap();
+//! assert_eq!(cut_sites("AAGAATTCAAAGAATTCA", &ecori).unwrap(), vec![3, 12]);
+//! assert_eq!(digest("AAGAATTCAAAGAATTCA", &[ecori]).unwrap(), vec![3, 9, 6]);
+//! ```
+//!
+//! # Overlap Graphs
+//! ## Examples
+//! ```
+//! use rosalind::grph::*;
+//!
+//! let dataset = ">Rosalind_0498
+//!   AAATAAA
+//!   >Rosalind_2391
+//!   AAATTTT
+//!   >Rosalind_2323
+//!   TTTTCCC
+//!   >Rosalind_0442
+//!   AAATCCC
+//!   >Rosalind_5013
+//!   GGGTGGG";
+//!
+//! let edges = overlap_graph_in_dataset(dataset, 3).unwrap();
+//! assert_eq!(edges.len(), 3);
+//! assert_eq!(edges[0], ("Rosalind_0498".to_string(), "Rosalind_2391".to_string()));
+//! ```
+//!
+//! # Finding a Shared Motif
+//! ## Examples
+//! ```
+//! use rosalind::lcsm::*;
+//!
+//! let dataset = ">Rosalind_1
+//!   GATTACA
+//!   >Rosalind_2
+//!   TAGACCA
+//!   >Rosalind_3
+//!   ATACA";
+//! assert_eq!(shared_motif_in_dataset(dataset).unwrap(), "AC");
+//! assert_eq!(shared_motif(&["GGATTACA", "CCATTACA"]).unwrap(), "ATTACA");
+//! ```
+//!
+//! # Genome Assembly as Shortest Superstring
+//! ## Examples
+//! ```
+//! use rosalind::long::*;
+//!
+//! let dataset = ">Rosalind_56
+//!   ATTAGACCTG
+//!   >Rosalind_57
+//!   CCTGCCGGAA
+//!   >Rosalind_58
+//!   AGACCTGCCG
+//!   >Rosalind_59
+//!   GCCGGAATAC
+//!   >Rosalind_60
+//!   TTTTTTTTTT";
+//!
+//! let assembly = assemble_dataset(dataset).unwrap();
+//! assert_eq!(assembly.superstring, "ATTAGACCTGCCGGAATAC");
+//! assert_eq!(assembly.unplaced, vec!["Rosalind_60"]);
+//! ```
+//!
+//! # Error Correction in Reads
+//! ## Examples
+//! ```
+//! use rosalind::corr::*;
+//!
+//! let dataset = ">Rosalind_52
+//!   TCATC
+//!   >Rosalind_44
+//!   TTCAT
+//!   >Rosalind_68
+//!   TCATC
+//!   >Rosalind_28
+//!   TGAAA
+//!   >Rosalind_95
+//!   GAGGA
+//!   >Rosalind_66
+//!   TTTCA
+//!   >Rosalind_33
+//!   ATCAA
+//!   >Rosalind_21
+//!   TTGAT
+//!   >Rosalind_18
+//!   TTTCC";
+//!
+//! let corrections = correct_reads_in_dataset(dataset).unwrap();
+//! assert_eq!(corrections[0].to_string(), "TTCAT->TTGAT");
+//! assert_eq!(corrections[0].id, "Rosalind_44");
+//! ```
+//!
+//! # Transitions and Transversions
+//! ## Examples
+//! ```
+//! use rosalind::tran::*;
+//!
+//! let mutations = transitions_and_transversions("GATTACA", "AACTAGA").unwrap();
+//! assert_eq!(mutations, PointMutations {transitions: 2, transversions: 1});
+//! assert_eq!(mutations.ratio(), 2.0);
+//! ```
+//!
+//! # Finding a Spliced Motif
+//! ## Examples
+//! ```
+//! use rosalind::sseq::*;
+//!
+//! let dataset = ">Rosalind_14
+//!   ACGTACGTGACG
+//!   >Rosalind_18
+//!   GTA";
+//!
+//! assert_eq!(spliced_motif_in_dataset(dataset).unwrap(), Some(vec![3, 4, 5]));
+//! assert!(is_spliced_motif("ACGTACGTGACG", "GTA"));
+//! ```
+//!
+//! # Finding a Shared Spliced Motif
+//! ## Examples
+//! ```
+//! use rosalind::lcsq::*;
+//!
+//! let dataset = ">Rosalind_23
+//!   AACCTTGG
+//!   >Rosalind_64
+//!   ACACTGTGA";
+//!
+//! let lcs = longest_common_subsequence_in_dataset(dataset).unwrap();
+//! assert_eq!(lcs.subsequence.len(), 6);
+//! assert_eq!(lcs.traceback.len(), 6);
+//! ```
+//!
+//! # Edit Distance and Edit Distance Alignment
+//! ## Examples
+//! ```
+//! use rosalind::edit::*;
+//!
+//! assert_eq!(edit_distance("PLEASANTLY", "MEANLY"), 5);
+//!
+//! let dataset = ">Rosalind_43
+//!   PRETTY
+//!   >Rosalind_97
+//!   PRTTEIN";
+//!
+//! let alignment = edit_alignment_in_dataset(dataset).unwrap();
+//! assert_eq!(alignment.distance, 4);
+//! assert_eq!(alignment.s_aligned.replace("-", ""), "PRETTY");
+//! ```
+//!
+//! # Global Alignment with Scoring Matrix and Affine Gap Penalty
+//! ## Examples
+//! ```
+//! use rosalind::align::*;
+//!
+//! let dataset = ">Rosalind_79
+//!   PLEASANTLY
+//!   >Rosalind_41
+//!   MEANLY";
+//!
+//! let blosum62 = ScoringMatrix::blosum62();
+//! assert_eq!(global_alignment_in_dataset(dataset, &blosum62, GapPenalty::Linear(5)).unwrap().score, 8);
+//!
+//! let gap = GapPenalty::Affine {open: 11, extend: 1};
+//! let alignment = global_alignment("PRTEINS", "PRTWPSEIN", &blosum62, gap).unwrap();
+//! assert_eq!(alignment.to_string(), "8\nPRT---EINS\nPRTWPSEIN-");
+//! ```
+//!
+//! # Local Alignment with Scoring Matrix and Affine Gap Penalty
+//! ## Examples
+//! ```
+//! use rosalind::align::*;
+//!
+//! let dataset = ">Rosalind_8
+//!   PLEASANTLY
+//!   >Rosalind_18
+//!   MEANLY";
+//!
+//! let gap = GapPenalty::Affine {open: 11, extend: 1};
+//! let alignment = local_alignment_in_dataset(dataset, &ScoringMatrix::blosum62(), gap).unwrap();
+//! assert_eq!(alignment.score, 12);
+//! assert_eq!((alignment.s_range, alignment.t_range), (1..5, 0..4));
+//! ```
+//!
+//! # Finding a Motif with Modifications and Overlap Alignment
+//! ## Examples
+//! ```
+//! use rosalind::align::*;
+//!
+//! let dataset = ">Rosalind_54
+//!   GCAAACCATAAGCCCTACGTGCCGCCTGTTTAAACTCGCGAACTGAATCTTCTGCTTCACGGTGAAAGTACCACAATGGTATCACACCCCAAGGAAAC
+//!   >Rosalind_46
+//!   GCCGTCAGGCTGGTGTCCG";
+//!
+//! let dna = ScoringMatrix::match_mismatch(1, -1);
+//! let alignment = align_in_dataset(dataset, &dna, GapPenalty::Linear(1), AlignmentMode::Fitting).unwrap();
+//! assert_eq!(alignment.score, 5);
+//!
+//! let dna = ScoringMatrix::match_mismatch(1, -2);
+//! let alignment = align("GGGGATTACA", "ATTACACCC", &dna, GapPenalty::Linear(2), AlignmentMode::Overlap).unwrap();
+//! assert_eq!((alignment.score, alignment.s_range, alignment.t_range), (6, 4..10, 0..6));
+//! ```
+//!
+//! # Global Multiple Alignment and progressive multiple alignment
+//! ## Examples
+//! ```
+//! use rosalind::align::ScoringMatrix;
+//! use rosalind::mult::*;
+//!
+//! let dataset = ">Rosalind_7
+//!   ATATCCG
+//!   >Rosalind_35
+//!   TCCG
+//!   >Rosalind_23
+//!   ATGTACTG
+//!   >Rosalind_44
+//!   ATGTCTG";
+//!
+//! let alignment = multiple_alignment_in_dataset(dataset, &ScoringMatrix::match_mismatch(0, -1), 1).unwrap();
+//! assert_eq!(alignment.score, -18);
+//!
+//! let homologs = ["GATTACA", "GATACA", "GATTACCA", "GTTACA"];
+//! let alignment = progressive_multiple_alignment(&homologs, &ScoringMatrix::match_mismatch(1, -1), 2).unwrap();
+//! assert_eq!(alignment.consensus().unwrap(), "GATTACA");
+//! ```
+//!
+//! # Typed nucleotides and validated DNA and RNA strings
+//! ## Examples
+//! ```
+//! use rosalind::RosalindError::UnknownNucleotide;
+//! use rosalind::dna::DNANucleotides;
+//! use rosalind::seq::*;
+//!
+//! let dna = DnaSeq::new("AAAACCCGGT").unwrap();
+//! assert_eq!(dna.count(), DNANucleotides {A: 4, C: 3, G: 2, T: 1});
+//! assert_eq!(dna.reverse_complement().to_string(), "ACCGGGTTTT");
+//! assert_eq!(dna.transcribe().bases()[9], RnaBase::U);
+//! assert_eq!(dna.gc_content(), 50f32);
+//! assert_eq!(DnaSeq::new("AZ").unwrap_err(), UnknownNucleotide('Z'));
+//! ```
+//!
+//! # IUPAC nucleotide ambiguity codes
+//! ## Examples
+//! ```
+//! use rosalind::dna::*;
+//! use rosalind::rna::*;
+//! use rosalind::revc::*;
+//! use rosalind::subs::*;
+//!
+//! assert_eq!(count_dna_nucleotides_iupac("AGCTNNNNRYAT").unwrap(),
+//!   IupacDNANucleotides {A: 2, C: 1, G: 1, T: 2, ambiguous: 6});
+//! assert_eq!(transcribe_dna_into_rna_iupac("GATNNRYT").unwrap(), "GAUNNRYU");
+//! assert_eq!(reverse_complement_dna_iupac("AAANNRKBD").unwrap(), "HVMYNNTTT");
+//! assert_eq!(motif_lookup_iupac("AGGACCTTGGTCCA", "GGNCC").unwrap(), vec![2, 9]);
+//! ```
+//!
+//! # Soft-masked DNA
+//! ## Examples
+//! ```
+//! use rosalind::dna::*;
+//! use rosalind::rna::*;
+//! use rosalind::revc::*;
+//! use rosalind::utils::*;
+//!
+//! let dna = "AAAAcccGGT";
+//! assert_eq!(count_dna_nucleotides(dna).unwrap(), DNANucleotides {A: 4, C: 3, G: 2, T: 1});
+//! assert_eq!(reverse_complement_dna(dna).unwrap(), "ACCGGGTTTT");
+//! assert_eq!(reverse_complement_dna_soft_masked(dna).unwrap(), "ACCgggTTTT");
+//! assert_eq!(transcribe_dna_into_rna_soft_masked("GATtaGT").unwrap(), "GAUuaGU");
+//! assert_eq!(soft_masked_intervals(dna), vec![4..7]);
+//! ```
+//!
+//! # 2-bit packed DNA
+//! ## Examples
+//! ```
+//! use rosalind::packed::*;
+//! use rosalind::revc::*;
+//! use rosalind::hamm::*;
+//!
+//! let dna = PackedDna::new("AAAACCCGGTNNA").unwrap();
+//! assert_eq!(dna.get(4), Some('C'));
+//! assert_eq!(dna.slice(7, 11).unwrap().to_string(), "GGTN");
+//! assert_eq!(reverse_complement_packed_dna(&dna).to_string(), "TNNACCGGGTTTT");
+//! assert_eq!(decode_kmer(dna.kmer(3, 3).unwrap(), 3), "ACC");
+//! let t = PackedDna::new("AAAACCCGGTNAA").unwrap();
+//! assert_eq!(hamming_distance_packed(&dna, &t).unwrap(), 1);
+//! ```
+//!
+//! # Reading and writing FASTA datasets
+//! ## Examples
+//! ```
+//! use rosalind::fasta::*;
+//!
+//! let dataset = ">Rosalind_1 first record
+//!   CCTGCGGAAG
+//!   TCCCACTAAT
+//!   >Rosalind_2
+//!   CCATCGGTAG";
+//!
+//! let mut reader = FastaReader::new(dataset.as_bytes());
+//! assert_eq!(reader.next().unwrap().unwrap(),
+//!   FastaRecord::new("Rosalind_1", "first record", "CCTGCGGAAGTCCCACTAAT"));
+//! assert_eq!(reader.next().unwrap().unwrap(), FastaRecord::new("Rosalind_2", "", "CCATCGGTAG"));
+//! assert!(reader.next().is_none());
+//!
+//! let mut writer = FastaWriter::with_line_width(Vec::new(), Some(4));
+//! writer.write_record(&FastaRecord::new("Rosalind_1", "", "CCTGCGGAAG")).unwrap();
+//! assert_eq!(writer.into_inner(), b">Rosalind_1\nCCTG\nCGGA\nAG\n");
+//!
+//! let mut reader = FastaReader::strict(">Rosalind_1\nCCTG\nCC1G\n".as_bytes());
+//! assert_eq!(reader.next().unwrap().unwrap_err(),
+//!   rosalind::RosalindError::FastaIllegalCharacter('1', 3, 3));
+//! ```
+//!
+//! # Reading and writing FASTQ datasets
+//! ## Examples
+//! ```
+//! use rosalind::fastq::*;
+//!
+//! let dataset = "@read_1 lane 1\nAGCTATAG\n+\n!+5?IIII\n";
+//! let mut reader = FastqReader::new(dataset.as_bytes());
+//! let record = reader.next().unwrap().unwrap();
+//!
+//! assert_eq!(record, FastqRecord::new("read_1", "lane 1", "AGCTATAG", "!+5?IIII"));
+//! assert_eq!(record.quality_scores(QualityEncoding::Phred33).unwrap(), vec![0, 10, 20, 30, 40, 40, 40, 40]);
+//!
+//! let mut writer = FastqWriter::new(Vec::new());
+//! writer.write_record(&record).unwrap();
+//! assert_eq!(writer.into_inner(), dataset.as_bytes());
+//! ```
+//!
 //! # Utilities
 //! ## Parse FASTA dataset into list of Strings
 //! ```
@@ -178,6 +579,7 @@ extern crate num;
 
 use std::error::Error;
 use std::fmt;
+use std::io;
 use std::result;
 
 use self::RosalindError::*;
@@ -191,6 +593,20 @@ pub enum RosalindError {
   HammingStringsLengthError,
   MotifStringsLengthError,
   InvalidInputParameters,
+  IoError(String),
+  FastaMissingLabel(usize),
+  FastaEmptyLabel(usize),
+  FastaEmptyRecord(String, usize),
+  FastaDuplicateId(String, usize),
+  FastaIllegalCharacter(char, usize, usize),
+  FastqParseError(usize),
+  FastqQualityLengthError(String, usize),
+  InvalidQualityScore(char),
+  UnknownGeneticCode(u8),
+  UnknownRestrictionEnzyme(String),
+  UnknownScoringSymbol(char),
+  ScoringMatrixParseError(usize),
+  ProfileStringsLengthError,
 }
 
 impl fmt::Display for RosalindError {
@@ -199,6 +615,23 @@ impl fmt::Display for RosalindError {
       UnknownNucleotide(ref nucleotide) => write!(f, "{}: '{}'", self.description(), nucleotide),
       UnknownCodon(ref codon) => write!(f, "{}: '{}'", self.description(), codon),
       UnknownAminoAcid(ref amino_acid) => write!(f, "{}: '{}'", self.description(), amino_acid),
+      IoError(ref message) => write!(f, "{}: {}", self.description(), message),
+      FastaMissingLabel(ref line) => write!(f, "{} at line {}", self.description(), line),
+      FastaEmptyLabel(ref line) => write!(f, "{} at line {}", self.description(), line),
+      FastaEmptyRecord(ref id, ref line) =>
+        write!(f, "{}: '{}' at line {}", self.description(), id, line),
+      FastaDuplicateId(ref id, ref line) =>
+        write!(f, "{}: '{}' at line {}", self.description(), id, line),
+      FastaIllegalCharacter(ref symbol, ref line, ref column) =>
+        write!(f, "{}: '{}' at line {}, column {}", self.description(), symbol, line, column),
+      FastqParseError(ref line) => write!(f, "{} at line {}", self.description(), line),
+      FastqQualityLengthError(ref id, ref line) =>
+        write!(f, "{}: '{}' at line {}", self.description(), id, line),
+      InvalidQualityScore(ref symbol) => write!(f, "{}: '{}'", self.description(), symbol),
+      UnknownGeneticCode(ref id) => write!(f, "{}: '{}'", self.description(), id),
+      UnknownRestrictionEnzyme(ref name) => write!(f, "{}: '{}'", self.description(), name),
+      UnknownScoringSymbol(ref symbol) => write!(f, "{}: '{}'", self.description(), symbol),
+      ScoringMatrixParseError(ref line) => write!(f, "{} at line {}", self.description(), line),
       _ => write!(f, "{}", self.description()),
     }
   }
@@ -213,11 +646,31 @@ impl Error for RosalindError {
       CodonParseError => "Could not parse RNA string and group codons",
       HammingStringsLengthError => "Strings must have equal length",
       MotifStringsLengthError => "Substrig `t` must be no longer than `s`",
-      InvalidInputParameters => "Invalid input parameters have been passed to the function"
+      InvalidInputParameters => "Invalid input parameters have been passed to the function",
+      IoError(..) => "Could not read or write dataset",
+      FastaMissingLabel(..) => "Sequence found before the first FASTA label",
+      FastaEmptyLabel(..) => "FASTA label has no identifier",
+      FastaEmptyRecord(..) => "FASTA record has no sequence",
+      FastaDuplicateId(..) => "Duplicate FASTA identifier",
+      FastaIllegalCharacter(..) => "Illegal character in FASTA sequence",
+      FastqParseError(..) => "Could not parse FASTQ record",
+      FastqQualityLengthError(..) => "FASTQ sequence and quality must have equal length",
+      InvalidQualityScore(..) => "Invalid quality score",
+      UnknownGeneticCode(..) => "Unknown NCBI translation table",
+      UnknownRestrictionEnzyme(..) => "Unknown restriction enzyme",
+      UnknownScoringSymbol(..) => "Symbol is not scored by scoring matrix",
+      ScoringMatrixParseError(..) => "Could not parse scoring matrix",
+      ProfileStringsLengthError => "Strings of profile must have equal length",
     }
   }
 }
 
+impl From<io::Error> for RosalindError {
+  fn from(err: io::Error) -> RosalindError {
+    IoError(err.to_string())
+  }
+}
+
 /// Unified return type for all modules and methods of `rosalind` library
 ///
 /// ## Examples
@@ -251,15 +704,41 @@ pub mod iprb;
 pub mod cons;
 pub mod constants;
 pub mod utils;
+pub mod fasta;
+pub mod fastq;
+pub mod seq;
+pub mod iupac;
+pub mod packed;
+pub mod orf;
+pub mod splc;
+pub mod restriction;
+pub mod grph;
+pub mod lcsm;
+pub mod long;
+pub mod corr;
+pub mod tran;
+pub mod sseq;
+pub mod lcsq;
+pub mod edit;
+pub mod align;
+pub mod mult;
 
 #[cfg(test)]
 mod tests {
   use super::RosalindError;
-  use super::RosalindError::CodonParseError;
+  use super::RosalindError::{CodonParseError, FastaIllegalCharacter, FastaDuplicateId};
 
   #[test]
   fn it_should_stringify_rosalind_error() {
     let error: RosalindError = CodonParseError;
     assert_eq!(error.to_string(), "Could not parse RNA string and group codons");
   }
+
+  #[test]
+  fn it_should_stringify_fasta_error_with_position() {
+    let error: RosalindError = FastaIllegalCharacter('1', 3, 5);
+    assert_eq!(error.to_string(), "Illegal character in FASTA sequence: '1' at line 3, column 5");
+    let error: RosalindError = FastaDuplicateId("Rosalind_1".to_string(), 7);
+    assert_eq!(error.to_string(), "Duplicate FASTA identifier: 'Rosalind_1' at line 7");
+  }
 }
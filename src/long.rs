@@ -0,0 +1,204 @@
+//! Module for `Genome Assembly as Shortest Superstring`
+
+use RosalindResult;
+use RosalindError::InvalidInputParameters;
+use fasta::{FastaReader, FastaRecord};
+
+/// This structure contains assembled superstring and IDs of reads which could
+/// not be placed into it
+#[derive(PartialEq, Debug)]
+pub struct Assembly {
+  pub superstring: String,
+  pub unplaced: Vec<String>,
+}
+
+// returns the longest overlap of suffix of `s` and prefix of `t` which is longer
+// than half of both reads, `t` must not be contained in `s` and vice versa
+fn overlap(s: &str, t: &str) -> Option<usize> {
+  let min_overlap = s.len().max(t.len()) / 2 + 1;
+  if min_overlap >= s.len().min(t.len()) { return None; }
+
+  let seed = &t[..min_overlap];
+  let mut from = s.len() - s.len().min(t.len()) + 1;
+  while let Some(i) = s[from..].find(seed) {
+    let position = from + i;
+    if s.len() - position < min_overlap { return None; }
+    if t.starts_with(&s[position..]) { return Some(s.len() - position); }
+    from = position + 1;
+  }
+  None
+}
+
+// checks whether the chain starting at `start` ends at `end`
+fn is_chain_end(successors: &[Option<(usize, usize)>], start: usize, end: usize) -> bool {
+  let mut read = start;
+  while let Some((next, _)) = successors[read] {
+    read = next;
+  }
+  read == end
+}
+
+/// This function assembles reads into the shortest superstring gluing together reads
+/// which overlap by more than half of their length, reads with the longest overlaps are
+/// glued first. Reads are case-insensitive. If reads form several contigs, the contig
+/// with the most reads is returned and reads from other contigs are reported as unplaced,
+/// reads contained in the superstring are considered placed.
+///
+/// ## Examples
+/// ```
+/// use rosalind::long::*;
+///
+/// let reads = vec![("1", "ATTAGACCTG"), ("2", "CCTGCCGGAA"), ("3", "AGACCTGCCG"), ("4", "GCCGGAATAC"), ("5", "TTTTTTTTTT")];
+/// let assembly = assemble_reads(&reads).unwrap();
+/// assert_eq!(assembly.superstring, "ATTAGACCTGCCGGAATAC");
+/// assert_eq!(assembly.unplaced, vec!["5"]);
+/// ```
+pub fn assemble_reads<S: AsRef<str>, T: AsRef<str>>(reads: &[(S, T)]) -> RosalindResult<Assembly> {
+  if reads.is_empty() { return Err(InvalidInputParameters); }
+  let seqs: Vec<String> = reads.iter().map(|(_, seq)| seq.as_ref().to_ascii_uppercase()).collect();
+
+  let mut overlaps = Vec::new();
+  for (i, s) in seqs.iter().enumerate() {
+    for (j, t) in seqs.iter().enumerate() {
+      if i == j { continue; }
+      if let Some(len) = overlap(s, t) { overlaps.push((len, i, j)); }
+    }
+  }
+  overlaps.sort_by(|a, b| b.0.cmp(&a.0).then((a.1, a.2).cmp(&(b.1, b.2))));
+
+  let mut successors: Vec<Option<(usize, usize)>> = vec![None; seqs.len()];
+  let mut has_predecessor = vec![false; seqs.len()];
+  for (len, i, j) in overlaps {
+    if successors[i].is_some() || has_predecessor[j] || is_chain_end(&successors, j, i) { continue; }
+    successors[i] = Some((j, len));
+    has_predecessor[j] = true;
+  }
+
+  // the contig with the most reads is assembled
+  let mut best_contig: Vec<usize> = Vec::new();
+  for start in (0..seqs.len()).filter(|&i| !has_predecessor[i]) {
+    let mut contig = vec![start];
+    while let Some((next, _)) = successors[*contig.last().unwrap()] {
+      contig.push(next);
+    }
+    if contig.len() > best_contig.len() { best_contig = contig; }
+  }
+
+  let mut superstring = seqs[best_contig[0]].clone();
+  for &read in best_contig.iter() {
+    if let Some((next, len)) = successors[read] { superstring.push_str(&seqs[next][len..]); }
+  }
+
+  let unplaced = (0..seqs.len())
+    .filter(|i| !best_contig.contains(i) && !superstring.contains(&*seqs[*i]))
+    .map(|i| reads[i].0.as_ref().to_string())
+    .collect();
+
+  Ok(Assembly {superstring, unplaced})
+}
+
+/// This function assembles reads from FASTA dataset into the shortest superstring
+///
+/// ## Examples
+/// ```
+/// use rosalind::long::*;
+///
+/// let dataset = ">Rosalind_56
+///   ATTAGACCTG
+///   >Rosalind_57
+///   CCTGCCGGAA
+///   >Rosalind_58
+///   AGACCTGCCG
+///   >Rosalind_59
+///   GCCGGAATAC";
+///
+/// assert_eq!(assemble_dataset(dataset).unwrap().superstring, "ATTAGACCTGCCGGAATAC");
+/// ```
+pub fn assemble_dataset(dataset: &str) -> RosalindResult<Assembly> {
+  assemble_records(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function assembles reads given by sequences of FASTA records into the shortest
+/// superstring
+///
+/// ## Examples
+/// ```
+/// use rosalind::long::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![Ok(FastaRecord::new("1", "", "GATTACA")), Ok(FastaRecord::new("2", "", "TTACAGG"))];
+/// assert_eq!(assemble_records(records).unwrap(), Assembly {superstring: "GATTACAGG".to_string(), unplaced: vec![]});
+/// ```
+pub fn assemble_records<I>(records: I) -> RosalindResult<Assembly>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+  let reads: Vec<(&str, &str)> = records.iter().map(|record| (&*record.id, &*record.seq)).collect();
+  assemble_reads(&reads)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::overlap;
+  use super::super::RosalindError::InvalidInputParameters;
+
+  const DATASET: &'static str = ">Rosalind_56
+ATTAGACCTG
+>Rosalind_57
+CCTGCCGGAA
+>Rosalind_58
+AGACCTGCCG
+>Rosalind_59
+GCCGGAATAC
+";
+
+  #[test]
+  fn it_should_find_overlaps_longer_than_half_of_reads() {
+    assert_eq!(overlap("ATTAGACCTG", "AGACCTGCCG"), Some(7));
+    assert_eq!(overlap("AGACCTGCCG", "ATTAGACCTG"), None);
+    assert_eq!(overlap("AAAAAAAAAA", "AAAAAAAAAC"), Some(9));
+    assert_eq!(overlap("ACGTACGTAC", "ACGTACGTAC"), Some(6));
+    assert_eq!(overlap("ACGTAC", "GTACGA"), Some(4));
+    assert_eq!(overlap("ACGTAC", "TACGAA"), None);
+  }
+
+  #[test]
+  fn it_should_assemble_dataset() {
+    let assembly = assemble_dataset(DATASET).unwrap();
+    assert_eq!(assembly, Assembly {superstring: "ATTAGACCTGCCGGAATAC".to_string(), unplaced: vec![]});
+  }
+
+  #[test]
+  fn it_should_assemble_shuffled_reads() {
+    let reads = vec![("4", "GCCGGAATAC"), ("2", "CCTGCCGGAA"), ("1", "ATTAGACCTG"), ("3", "AGACCTGCCG")];
+    assert_eq!(assemble_reads(&reads).unwrap().superstring, "ATTAGACCTGCCGGAATAC");
+  }
+
+  #[test]
+  fn it_should_report_unplaced_reads() {
+    let reads = vec![("1", "ATTAGACCTG"), ("2", "AGACCTGCCG"), ("3", "GGGGGGTTTT"), ("4", "GGGTTTTCCC")];
+    let assembly = assemble_reads(&reads).unwrap();
+    assert_eq!(assembly.superstring, "ATTAGACCTGCCG");
+    assert_eq!(assembly.unplaced, vec!["3", "4"]);
+  }
+
+  #[test]
+  fn it_should_place_contained_reads() {
+    let reads = vec![("1", "ATTAGACCTG"), ("2", "AGACCTGCCG"), ("3", "TAGACC")];
+    let assembly = assemble_reads(&reads).unwrap();
+    assert_eq!(assembly, Assembly {superstring: "ATTAGACCTGCCG".to_string(), unplaced: vec![]});
+  }
+
+  #[test]
+  fn it_should_not_glue_reads_into_cycle() {
+    let reads = vec![("1", "ACGTAC"), ("2", "GTACGT"), ("3", "ACGTAC")];
+    let assembly = assemble_reads(&reads).unwrap();
+    assert!(assembly.superstring.contains("ACGTAC") && assembly.superstring.contains("GTACGT"));
+    assert_eq!(assembly.unplaced, Vec::<String>::new());
+  }
+
+  #[test]
+  fn it_should_return_error_when_no_reads_given() {
+    assert_eq!(assemble_dataset("").unwrap_err(), InvalidInputParameters);
+  }
+}
@@ -0,0 +1,599 @@
+//! Module for `Global Multiple Alignment` and progressive multiple alignment
+
+use std::fmt;
+use RosalindResult;
+use RosalindError::{InvalidInputParameters, ProfileStringsLengthError, UnknownScoringSymbol};
+use constants::GAP_SYMBOL;
+use fasta::{FastaReader, FastaRecord};
+use align::ScoringMatrix;
+use cons::{consensus, profile};
+
+/// Maximal number of transitions of exact dynamic programming, `multiple_alignment`
+/// switches to progressive alignment beyond it
+pub const EXACT_ALIGNMENT_LIMIT: usize = 1 << 24;
+
+/// This structure contains sum-of-pairs score of multiple alignment and aligned strings
+/// in the order of input strings, gaps in aligned strings are denoted by `-`
+#[derive(PartialEq, Debug)]
+pub struct MultipleAlignment {
+  pub score: i32,
+  pub aligned: Vec<String>,
+}
+
+impl MultipleAlignment {
+  /// This function calculates consensus string of aligned DNA strings,
+  /// columns where most of strings have gaps are skipped. Rows of different length give
+  /// `ProfileStringsLengthError` and symbols other than nucleotides `UnknownNucleotide`
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::align::ScoringMatrix;
+  /// use rosalind::mult::*;
+  ///
+  /// let dna = ScoringMatrix::match_mismatch(1, -1);
+  /// let alignment = multiple_alignment(&["GATTACA", "GATACA", "GATTACCA"], &dna, 2).unwrap();
+  /// assert_eq!(alignment.consensus().unwrap(), "GATTACA");
+  /// ```
+  pub fn consensus(&self) -> RosalindResult<String> {
+    let rows: Vec<Vec<char>> = self.aligned.iter().map(|aligned| aligned.chars().collect()).collect();
+    let len = rows.first().map_or(0, |row| row.len());
+    if rows.iter().any(|row| row.len() != len) { return Err(ProfileStringsLengthError); }
+    let columns: Vec<usize> = (0..len).filter(|&k| {
+      2 * rows.iter().filter(|row| row[k] == GAP_SYMBOL).count() <= rows.len()
+    }).collect();
+
+    let aligned: Vec<String> = rows.iter().map(|row| columns.iter().map(|&k| row[k]).collect()).collect();
+    consensus(try!(profile(aligned.iter().map(|aligned| aligned.as_str()).collect())))
+  }
+}
+
+impl fmt::Display for MultipleAlignment {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    try!(write!(f, "{}", self.score));
+    for aligned in &self.aligned {
+      try!(write!(f, "\n{}", aligned));
+    }
+    Ok(())
+  }
+}
+
+// symbols of strings replaced by their indices in the table of scores
+struct Encoded {
+  strings: Vec<Vec<usize>>,
+  symbols: Vec<char>,
+  scores: Vec<Vec<i32>>,
+}
+
+fn encode<S: AsRef<str>>(strings: &[S], matrix: &ScoringMatrix) -> RosalindResult<Encoded> {
+  let mut symbols: Vec<char> = Vec::new();
+  let mut encoded = Vec::with_capacity(strings.len());
+  for string in strings {
+    // gap symbol in input strings would be indistinguishable from gaps of alignment
+    if string.as_ref().contains(GAP_SYMBOL) { return Err(UnknownScoringSymbol(GAP_SYMBOL)); }
+    encoded.push(string.as_ref().chars().map(|symbol| {
+      symbols.iter().position(|&known| known == symbol).unwrap_or_else(|| {
+        symbols.push(symbol);
+        symbols.len() - 1
+      })
+    }).collect());
+  }
+
+  let mut scores = vec![vec![0; symbols.len()]; symbols.len()];
+  for (i, &a) in symbols.iter().enumerate() {
+    for (j, &b) in symbols.iter().enumerate() {
+      scores[i][j] = try!(matrix.score(a, b));
+    }
+  }
+  Ok(Encoded {strings: encoded, symbols, scores})
+}
+
+fn decode(rows: Vec<Vec<Option<usize>>>, symbols: &[char]) -> Vec<String> {
+  rows.into_iter().map(|row| {
+    row.into_iter().map(|code| code.map_or(GAP_SYMBOL, |code| symbols[code])).collect()
+  }).collect()
+}
+
+fn exact_transitions(strings: &[Vec<usize>]) -> Option<usize> {
+  let subsets = if strings.len() < 32 { (1usize << strings.len()) - 1 } else { return None; };
+  strings.iter().try_fold(subsets, |transitions, string| transitions.checked_mul(string.len() + 1))
+}
+
+// dynamic programming over all cells of `k`-dimensional table, the move to a cell is given
+// by the subset of strings contributing a symbol to the column, the others contribute gaps
+fn exact_alignment(encoded: &Encoded, gap: i32) -> Vec<Vec<Option<usize>>> {
+  let strings = &encoded.strings;
+  let k = strings.len();
+  let dims: Vec<usize> = strings.iter().map(|string| string.len() + 1).collect();
+  let mut strides = vec![1; k];
+  for i in 1..k { strides[i] = strides[i - 1] * dims[i - 1]; }
+  let cells = dims.iter().product();
+
+  let mut best = vec![i32::MIN; cells];
+  let mut moves = vec![0u32; cells];
+  best[0] = 0;
+  let mut coords = vec![0; k];
+  for cell in 1..cells {
+    let mut rest = cell;
+    for (coord, &dim) in coords.iter_mut().zip(&dims) {
+      *coord = rest % dim;
+      rest /= dim;
+    }
+
+    for mask in 1u32..(1 << k) {
+      let in_column = |i: usize| mask >> i & 1 == 1;
+      if (0..k).any(|i| in_column(i) && coords[i] == 0) { continue; }
+
+      let mut previous = cell;
+      let mut score = 0;
+      for i in 0..k {
+        if !in_column(i) { continue; }
+        previous -= strides[i];
+        for j in 0..k {
+          if j == i { continue; }
+          if !in_column(j) {
+            score -= gap;
+          } else if j > i {
+            score += encoded.scores[strings[i][coords[i] - 1]][strings[j][coords[j] - 1]];
+          }
+        }
+      }
+
+      if best[previous] + score > best[cell] {
+        best[cell] = best[previous] + score;
+        moves[cell] = mask;
+      }
+    }
+  }
+
+  let mut rows = vec![Vec::new(); k];
+  let mut cell = cells - 1;
+  while cell > 0 {
+    let mut rest = cell;
+    for (coord, &dim) in coords.iter_mut().zip(&dims) {
+      *coord = rest % dim;
+      rest /= dim;
+    }
+    let mask = moves[cell];
+    for (i, row) in rows.iter_mut().enumerate() {
+      if mask >> i & 1 == 1 {
+        row.push(Some(strings[i][coords[i] - 1]));
+        cell -= strides[i];
+      } else {
+        row.push(None);
+      }
+    }
+  }
+  for row in &mut rows { row.reverse(); }
+  rows
+}
+
+// cluster of guide tree, i.e. alignment of its member strings stored by columns
+struct Cluster {
+  members: Vec<usize>,
+  columns: Vec<Vec<Option<usize>>>,
+}
+
+// numbers of symbols in a column of cluster and the number of its gaps
+struct ColumnCounts {
+  symbols: Vec<(usize, i32)>,
+  residues: i32,
+  gaps: i32,
+}
+
+fn column_counts(column: &[Option<usize>]) -> ColumnCounts {
+  let mut counts = ColumnCounts {symbols: Vec::new(), residues: 0, gaps: 0};
+  for code in column {
+    match *code {
+      Some(code) => {
+        counts.residues += 1;
+        match counts.symbols.iter().position(|&(symbol, _)| symbol == code) {
+          Some(k) => counts.symbols[k].1 += 1,
+          None => counts.symbols.push((code, 1)),
+        }
+      },
+      None => counts.gaps += 1,
+    }
+  }
+  counts
+}
+
+// sum-of-pairs alignment of two clusters with fixed columns, i.e. alignment of profiles
+fn align_clusters(a: &Cluster, b: &Cluster, scores: &[Vec<i32>], gap: i32) -> Cluster {
+  const FROM_DIAGONAL: u8 = 0;
+  const FROM_UP: u8 = 1;
+  const FROM_LEFT: u8 = 2;
+
+  let a_counts: Vec<ColumnCounts> = a.columns.iter().map(|column| column_counts(column)).collect();
+  let b_counts: Vec<ColumnCounts> = b.columns.iter().map(|column| column_counts(column)).collect();
+  let (a_size, b_size) = (a.members.len() as i32, b.members.len() as i32);
+  let pair_score = |x: &ColumnCounts, y: &ColumnCounts| {
+    let mut score = -gap * (x.residues * y.gaps + x.gaps * y.residues);
+    for &(u, u_count) in &x.symbols {
+      for &(v, v_count) in &y.symbols {
+        score += u_count * v_count * scores[u][v];
+      }
+    }
+    score
+  };
+
+  let width = b_counts.len() + 1;
+  let mut table = vec![0; (a_counts.len() + 1) * width];
+  let mut moves = vec![FROM_DIAGONAL; table.len()];
+  for j in 0..b_counts.len() {
+    table[j + 1] = table[j] - gap * b_counts[j].residues * a_size;
+    moves[j + 1] = FROM_LEFT;
+  }
+  for (i, x) in a_counts.iter().enumerate() {
+    table[(i + 1) * width] = table[i * width] - gap * x.residues * b_size;
+    moves[(i + 1) * width] = FROM_UP;
+    for (j, y) in b_counts.iter().enumerate() {
+      let diagonal = table[i * width + j] + pair_score(x, y);
+      let up = table[i * width + j + 1] - gap * x.residues * b_size;
+      let left = table[(i + 1) * width + j] - gap * y.residues * a_size;
+      let cell = (i + 1) * width + j + 1;
+      if diagonal >= up && diagonal >= left {
+        table[cell] = diagonal;
+      } else if up >= left {
+        table[cell] = up;
+        moves[cell] = FROM_UP;
+      } else {
+        table[cell] = left;
+        moves[cell] = FROM_LEFT;
+      }
+    }
+  }
+
+  let mut columns = Vec::with_capacity(a_counts.len() + b_counts.len());
+  let (mut i, mut j) = (a_counts.len(), b_counts.len());
+  while i > 0 || j > 0 {
+    let mut column = Vec::with_capacity(a.members.len() + b.members.len());
+    match moves[i * width + j] {
+      FROM_DIAGONAL => {
+        i -= 1;
+        j -= 1;
+        column.extend_from_slice(&a.columns[i]);
+        column.extend_from_slice(&b.columns[j]);
+      },
+      FROM_UP => {
+        i -= 1;
+        column.extend_from_slice(&a.columns[i]);
+        column.extend(b.members.iter().map(|_| None));
+      },
+      _ => {
+        j -= 1;
+        column.extend(a.members.iter().map(|_| None));
+        column.extend_from_slice(&b.columns[j]);
+      },
+    }
+    columns.push(column);
+  }
+  columns.reverse();
+
+  let mut members = a.members.clone();
+  members.extend_from_slice(&b.members);
+  Cluster {members, columns}
+}
+
+fn singleton(encoded: &Encoded, member: usize) -> Cluster {
+  Cluster {
+    members: vec![member],
+    columns: encoded.strings[member].iter().map(|&code| vec![Some(code)]).collect(),
+  }
+}
+
+// UPGMA guide tree on distances of pairwise alignments, clusters are aligned when joined
+fn progressive_alignment(encoded: &Encoded, gap: i32) -> Vec<Vec<Option<usize>>> {
+  let n = encoded.strings.len();
+  let mut clusters: Vec<Option<Cluster>> = (0..n).map(|member| Some(singleton(encoded, member))).collect();
+
+  // distance of two strings is the fraction of mismatched columns in their alignment
+  let mut distances = vec![vec![0.0; n]; n];
+  for (i, j) in (0..n).flat_map(|i| ((i + 1)..n).map(move |j| (i, j))) {
+    let pair = align_clusters(&singleton(encoded, i), &singleton(encoded, j), &encoded.scores, gap);
+    let matches = pair.columns.iter().filter(|column| column[0].is_some() && column[0] == column[1]).count();
+    let distance = if pair.columns.is_empty() { 0.0 } else { 1.0 - matches as f64 / pair.columns.len() as f64 };
+    distances[i][j] = distance;
+    distances[j][i] = distance;
+  }
+
+  for _ in 1..n {
+    let mut closest: Option<(usize, usize)> = None;
+    for i in (0..n).filter(|&i| clusters[i].is_some()) {
+      for j in ((i + 1)..n).filter(|&j| clusters[j].is_some()) {
+        if closest.map_or(true, |(k, l)| distances[i][j] < distances[k][l]) { closest = Some((i, j)); }
+      }
+    }
+    let (i, j) = closest.unwrap();
+
+    let (a, b) = (clusters[i].take().unwrap(), clusters[j].take().unwrap());
+    let (a_size, b_size) = (a.members.len() as f64, b.members.len() as f64);
+    for k in (0..n).filter(|&k| clusters[k].is_some()) {
+      let distance = (a_size * distances[i][k] + b_size * distances[j][k]) / (a_size + b_size);
+      distances[i][k] = distance;
+      distances[k][i] = distance;
+    }
+    clusters[i] = Some(align_clusters(&a, &b, &encoded.scores, gap));
+  }
+
+  let root = clusters.into_iter().flatten().next().unwrap();
+  let mut rows = vec![Vec::new(); n];
+  for (k, &member) in root.members.iter().enumerate() {
+    rows[member] = root.columns.iter().map(|column| column[k]).collect();
+  }
+  rows
+}
+
+fn multiple_alignment_with<S, F>(strings: &[S], matrix: &ScoringMatrix, gap: i32, aligner: F) -> RosalindResult<MultipleAlignment>
+  where S: AsRef<str>, F: Fn(&Encoded) -> RosalindResult<Vec<Vec<Option<usize>>>> {
+  if strings.is_empty() { return Err(InvalidInputParameters); }
+  let encoded = try!(encode(strings, matrix));
+  let aligned = decode(try!(aligner(&encoded)), &encoded.symbols);
+  let score = try!(sum_of_pairs_score(&aligned, matrix, gap));
+  Ok(MultipleAlignment {score, aligned})
+}
+
+/// This function calculates sum-of-pairs score of aligned strings of equal length, i.e.
+/// the sum of scores of all pairs of symbols in each column, where a symbol against
+/// a gap `-` is penalized by linear `gap` penalty and two gaps score zero
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::align::ScoringMatrix;
+/// use rosalind::mult::*;
+///
+/// let dna = ScoringMatrix::match_mismatch(0, -1);
+/// assert_eq!(sum_of_pairs_score(&["AT-ATCCG", "--T--CCG", "ATGTACTG"], &dna, 1).unwrap(), -15);
+/// assert_eq!(sum_of_pairs_score(&["ACGT", "ACG"], &dna, 1).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn sum_of_pairs_score<S: AsRef<str>>(aligned: &[S], matrix: &ScoringMatrix, gap: i32) -> RosalindResult<i32> {
+  let rows: Vec<Vec<char>> = aligned.iter().map(|row| row.as_ref().chars().collect()).collect();
+  if rows.iter().any(|row| row.len() != rows[0].len()) { return Err(InvalidInputParameters); }
+
+  let mut score = 0;
+  for (i, s) in rows.iter().enumerate() {
+    for t in &rows[(i + 1)..] {
+      for (&a, &b) in s.iter().zip(t) {
+        score += match (a == GAP_SYMBOL, b == GAP_SYMBOL) {
+          (true, true) => 0,
+          (true, false) | (false, true) => -gap,
+          (false, false) => try!(matrix.score(a, b)),
+        };
+      }
+    }
+  }
+  Ok(score)
+}
+
+/// This function finds multiple alignment of strings maximizing sum-of-pairs score with
+/// linear `gap` penalty by dynamic programming over all prefixes of strings. The running time
+/// is exponential in the number of strings, so the number of transitions is limited
+/// by `EXACT_ALIGNMENT_LIMIT`, larger inputs and an empty list of strings are an error.
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::ScoringMatrix;
+/// use rosalind::mult::*;
+///
+/// let dna = ScoringMatrix::match_mismatch(0, -1);
+/// let alignment = exact_multiple_alignment(&["ATATCCG", "TCCG", "ATGTACTG", "ATGTCTG"], &dna, 1).unwrap();
+/// assert_eq!(alignment.score, -18);
+/// assert_eq!(alignment.aligned[1].replace("-", ""), "TCCG");
+/// ```
+pub fn exact_multiple_alignment<S: AsRef<str>>(strings: &[S], matrix: &ScoringMatrix, gap: i32) -> RosalindResult<MultipleAlignment> {
+  multiple_alignment_with(strings, matrix, gap, |encoded| {
+    match exact_transitions(&encoded.strings) {
+      Some(transitions) if transitions <= EXACT_ALIGNMENT_LIMIT => Ok(exact_alignment(encoded, gap)),
+      _ => Err(InvalidInputParameters),
+    }
+  })
+}
+
+/// This function finds multiple alignment of strings progressively: strings are joined into
+/// UPGMA guide tree by distances of their pairwise alignments and alignments of joined
+/// clusters are aligned maximizing sum-of-pairs score with linear `gap` penalty. The alignment
+/// is not guaranteed to be optimal, an empty list of strings is an error.
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::ScoringMatrix;
+/// use rosalind::mult::*;
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -1);
+/// let alignment = progressive_multiple_alignment(&["GATTACA", "GATACA", "GATTTACA"], &dna, 2).unwrap();
+/// assert_eq!(alignment.aligned, vec!["GA-TTACA", "GA--TACA", "GATTTACA"]);
+/// ```
+pub fn progressive_multiple_alignment<S: AsRef<str>>(strings: &[S], matrix: &ScoringMatrix, gap: i32) -> RosalindResult<MultipleAlignment> {
+  multiple_alignment_with(strings, matrix, gap, |encoded| Ok(progressive_alignment(encoded, gap)))
+}
+
+/// This function finds multiple alignment of strings maximizing sum-of-pairs score with
+/// linear `gap` penalty, it is exact when the number of transitions of dynamic programming
+/// is within `EXACT_ALIGNMENT_LIMIT` and progressive otherwise
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::ScoringMatrix;
+/// use rosalind::mult::*;
+///
+/// let dna = ScoringMatrix::match_mismatch(0, -1);
+/// let alignment = multiple_alignment(&["ATATCCG", "TCCG", "ATGTACTG", "ATGTCTG"], &dna, 1).unwrap();
+/// assert_eq!(alignment.score, -18);
+/// ```
+pub fn multiple_alignment<S: AsRef<str>>(strings: &[S], matrix: &ScoringMatrix, gap: i32) -> RosalindResult<MultipleAlignment> {
+  multiple_alignment_with(strings, matrix, gap, |encoded| {
+    match exact_transitions(&encoded.strings) {
+      Some(transitions) if transitions <= EXACT_ALIGNMENT_LIMIT => Ok(exact_alignment(encoded, gap)),
+      _ => Ok(progressive_alignment(encoded, gap)),
+    }
+  })
+}
+
+/// This function finds multiple alignment of strings in FASTA dataset
+///
+/// ## Examples
+/// ```
+/// use rosalind::align::ScoringMatrix;
+/// use rosalind::mult::*;
+///
+/// let dataset = ">Rosalind_7
+///   ATATCCG
+///   >Rosalind_35
+///   TCCG
+///   >Rosalind_23
+///   ATGTACTG
+///   >Rosalind_44
+///   ATGTCTG";
+///
+/// let dna = ScoringMatrix::match_mismatch(0, -1);
+/// assert_eq!(multiple_alignment_in_dataset(dataset, &dna, 1).unwrap().score, -18);
+/// ```
+pub fn multiple_alignment_in_dataset(dataset: &str, matrix: &ScoringMatrix, gap: i32) -> RosalindResult<MultipleAlignment> {
+  multiple_alignment_in_records(FastaReader::new(dataset.as_bytes()), matrix, gap)
+}
+
+/// This function aligns sequences of all given FASTA records together, at least one
+/// record is required
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::align::ScoringMatrix;
+/// use rosalind::mult::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let dna = ScoringMatrix::match_mismatch(1, -1);
+/// let records = vec![Ok(FastaRecord::new("s", "", "GATTACA")), Ok(FastaRecord::new("t", "", "GATACA"))];
+/// assert_eq!(multiple_alignment_in_records(records, &dna, 1).unwrap().to_string(), "5\nGATTACA\nGAT-ACA");
+/// assert_eq!(multiple_alignment_in_records(vec![], &dna, 1).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn multiple_alignment_in_records<I>(records: I, matrix: &ScoringMatrix, gap: i32) -> RosalindResult<MultipleAlignment>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+  let strings: Vec<&str> = records.iter().map(|record| record.seq.as_str()).collect();
+  multiple_alignment(&strings, matrix, gap)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use align::ScoringMatrix;
+  use super::super::RosalindError::{InvalidInputParameters, ProfileStringsLengthError, UnknownNucleotide, UnknownScoringSymbol};
+  use utils::{next_random, random_string};
+
+  fn mutate(dna: &str, seed: &mut u32) -> String {
+    dna.chars().filter_map(|nucleotide| {
+      match next_random(seed) % 40 {
+        0 => None,
+        1 => Some('T'),
+        _ => Some(nucleotide),
+      }
+    }).collect()
+  }
+
+  fn assert_multiple_alignment(strings: &[&str], matrix: &ScoringMatrix, gap: i32, alignment: &MultipleAlignment) {
+    assert_eq!(alignment.aligned.len(), strings.len());
+    for (aligned, &string) in alignment.aligned.iter().zip(strings) {
+      assert_eq!(aligned.len(), alignment.aligned[0].len());
+      assert_eq!(aligned.replace("-", ""), string);
+    }
+    assert!((0..alignment.aligned[0].len()).all(|k| alignment.aligned.iter().any(|aligned| aligned.as_bytes()[k] != b'-')));
+    assert_eq!(sum_of_pairs_score(&alignment.aligned, matrix, gap).unwrap(), alignment.score);
+  }
+
+  #[test]
+  fn it_should_find_exact_multiple_alignment() {
+    let strings = ["ATATCCG", "TCCG", "ATGTACTG", "ATGTCTG"];
+    let dna = ScoringMatrix::match_mismatch(0, -1);
+    let alignment = exact_multiple_alignment(&strings, &dna, 1).unwrap();
+    assert_multiple_alignment(&strings, &dna, 1, &alignment);
+    assert_eq!(alignment.score, -18);
+  }
+
+  #[test]
+  fn it_should_agree_with_pairwise_global_alignment() {
+    use align::{global_alignment, GapPenalty};
+    let blosum62 = ScoringMatrix::blosum62();
+    let alignment = exact_multiple_alignment(&["PLEASANTLY", "MEANLY"], &blosum62, 5).unwrap();
+    let pairwise = global_alignment("PLEASANTLY", "MEANLY", &blosum62, GapPenalty::Linear(5)).unwrap();
+    assert_eq!(alignment.score, pairwise.score);
+    assert_eq!(alignment.score, 8);
+  }
+
+  #[test]
+  fn it_should_align_strings_with_empty_string() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let alignment = exact_multiple_alignment(&["ACG", "", "AG"], &dna, 1).unwrap();
+    assert_multiple_alignment(&["ACG", "", "AG"], &dna, 1, &alignment);
+    assert_eq!(alignment.aligned, vec!["ACG", "---", "A-G"]);
+    assert_eq!(alignment.score, -4);
+  }
+
+  #[test]
+  fn it_should_align_single_string() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    assert_eq!(exact_multiple_alignment(&["ACGT"], &dna, 1).unwrap(), MultipleAlignment {score: 0, aligned: vec!["ACGT".to_string()]});
+    assert_eq!(progressive_multiple_alignment(&["ACGT"], &dna, 1).unwrap(), MultipleAlignment {score: 0, aligned: vec!["ACGT".to_string()]});
+  }
+
+  #[test]
+  fn it_should_find_progressive_alignment_close_to_exact() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let mut seed = 11;
+    let ancestor = random_string("ACGT", 12, &mut seed);
+    let descendants: Vec<String> = (0..4).map(|_| mutate(&ancestor, &mut seed)).collect();
+    let strings: Vec<&str> = descendants.iter().map(|string| string.as_str()).collect();
+
+    let exact = exact_multiple_alignment(&strings, &dna, 2).unwrap();
+    let progressive = progressive_multiple_alignment(&strings, &dna, 2).unwrap();
+    assert_multiple_alignment(&strings, &dna, 2, &exact);
+    assert_multiple_alignment(&strings, &dna, 2, &progressive);
+    assert!(progressive.score <= exact.score);
+    assert!(progressive.score >= exact.score - 4);
+  }
+
+  #[test]
+  fn it_should_align_many_strings_progressively() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let mut seed = 5;
+    let ancestor = random_string("ACGT", 300, &mut seed);
+    let descendants: Vec<String> = (0..20).map(|_| mutate(&ancestor, &mut seed)).collect();
+    let strings: Vec<&str> = descendants.iter().map(|string| string.as_str()).collect();
+
+    let alignment = multiple_alignment(&strings, &dna, 2).unwrap();
+    assert_multiple_alignment(&strings, &dna, 2, &alignment);
+    assert_eq!(alignment.consensus().unwrap(), ancestor);
+  }
+
+  #[test]
+  fn it_should_return_error_when_exact_alignment_is_too_large() {
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let strings = vec!["ACGTACGTACGTACGTACGT"; 8];
+    assert_eq!(exact_multiple_alignment(&strings, &dna, 1).unwrap_err(), InvalidInputParameters);
+    assert_eq!(multiple_alignment(&strings, &dna, 1).unwrap().aligned, strings);
+  }
+
+  #[test]
+  fn it_should_return_error_when_symbol_is_not_scored() {
+    let blosum62 = ScoringMatrix::blosum62();
+    assert_eq!(multiple_alignment(&["PRT", "PR1"], &blosum62, 5).unwrap_err(), UnknownScoringSymbol('1'));
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    assert_eq!(multiple_alignment(&["A-C", "AC"], &dna, 1).unwrap_err(), UnknownScoringSymbol('-'));
+    let strings: [&str; 0] = [];
+    assert_eq!(multiple_alignment(&strings, &blosum62, 5).unwrap_err(), InvalidInputParameters);
+  }
+
+  #[test]
+  fn it_should_return_error_when_consensus_is_not_of_equal_dna_rows() {
+    let alignment = |aligned: &[&str]| MultipleAlignment {score: 0, aligned: aligned.iter().map(|row| row.to_string()).collect()};
+    assert_eq!(alignment(&["AC-", "A"]).consensus().unwrap_err(), ProfileStringsLengthError);
+    assert_eq!(alignment(&["AÉ", "AC"]).consensus().unwrap_err(), UnknownNucleotide('É'));
+    assert_eq!(alignment(&["PR-", "P-R"]).consensus().unwrap_err(), UnknownNucleotide('P'));
+  }
+
+  #[test]
+  fn it_should_display_multiple_alignment() {
+    let alignment = MultipleAlignment {score: -2, aligned: vec!["AC-".to_string(), "A-G".to_string()]};
+    assert_eq!(alignment.to_string(), "-2\nAC-\nA-G");
+  }
+}
@@ -0,0 +1,213 @@
+//! Module for `Open Reading Frames`
+
+use std::ops::Range;
+use RosalindResult;
+use revc::reverse_complement_dna_iupac;
+use prot::{GeneticCode, Strand, translate_frame};
+use constants::PROTEIN_STOP_SYMBOL;
+
+/// This structure contains options of open reading frames lookup
+#[derive(Clone, PartialEq, Debug)]
+pub struct OrfOptions {
+  /// Minimum length of protein in amino acids
+  pub min_length: usize,
+  /// Whether open reading frames starting inside of other open reading frames are reported
+  pub nested: bool,
+  /// Genetic code used for translation
+  pub code: GeneticCode,
+  /// Whether all start codons of the genetic code initiate open reading frames,
+  /// otherwise only `ATG` does
+  pub alternative_starts: bool,
+}
+
+impl Default for OrfOptions {
+  fn default() -> OrfOptions {
+    OrfOptions {min_length: 1, nested: true, code: GeneticCode::standard(), alternative_starts: false}
+  }
+}
+
+/// This structure represents open reading frame, `location` is zero-based half-open
+/// range of nucleotides on the forward strand including stop codon
+#[derive(Clone, PartialEq, Debug)]
+pub struct Orf {
+  pub strand: Strand,
+  pub location: Range<usize>,
+  pub protein: String,
+}
+
+// ambiguous codons never initiate open reading frames
+fn is_start_codon(codon: &str, options: &OrfOptions) -> bool {
+  if !options.alternative_starts { return codon == "ATG"; }
+  options.code.is_start_codon(&codon.replace('T', "U")).unwrap_or(false)
+}
+
+// finds open reading frames on one strand, locations are relative to the strand
+fn find_strand_orfs(dna: &str, options: &OrfOptions) -> RosalindResult<Vec<(Range<usize>, String)>> {
+  let mut orfs = Vec::new();
+  for offset in 0..3 {
+    let frame = dna.get(offset..).unwrap_or("");
+    let protein: Vec<char> = try!(translate_frame(frame, &options.code)).chars().collect();
+
+    let mut stop = None;
+    let mut frame_orfs = Vec::new();
+    for i in (0..protein.len()).rev() {
+      if protein[i] == PROTEIN_STOP_SYMBOL {
+        stop = Some(i);
+      } else if let Some(stop) = stop {
+        if is_start_codon(&frame[i * 3..i * 3 + 3], options) && stop - i >= options.min_length {
+          let location = offset + i * 3..offset + stop * 3 + 3;
+          // alternative start codons are translated into methionine as initiators
+          let protein: String = ::std::iter::once('M').chain(protein[i + 1..stop].iter().cloned()).collect();
+          frame_orfs.push((location, protein));
+        }
+      }
+    }
+
+    frame_orfs.reverse();
+    if !options.nested { frame_orfs.dedup_by_key(|orf: &mut (Range<usize>, String)| orf.0.end); }
+    orfs.extend(frame_orfs);
+  }
+
+  orfs.sort_by_key(|orf| (orf.0.start, orf.0.end));
+  Ok(orfs)
+}
+
+/// This function finds open reading frames (from `ATG`, or any start codon of the genetic
+/// code with `alternative_starts`, to stop codon) on both strands of DNA string, nucleotides
+/// are case-insensitive. Open reading frames on the forward strand are followed by frames
+/// on the reverse strand, both are ordered by location.
+///
+/// ## Examples
+/// ```
+/// use rosalind::orf::*;
+/// use rosalind::prot::Strand;
+///
+/// let orfs = find_orfs("CCATGAAATAGTTACATTT", &OrfOptions::default()).unwrap();
+/// assert_eq!(orfs, vec![
+///   Orf {strand: Strand::Forward, location: 2..11, protein: "MK".to_string()},
+///   Orf {strand: Strand::Reverse, location: 11..17, protein: "M".to_string()},
+/// ]);
+///
+/// let options = OrfOptions {alternative_starts: true, ..OrfOptions::default()};
+/// let orfs = find_orfs("CCTTGAAATAG", &options).unwrap();
+/// assert_eq!(orfs, vec![Orf {strand: Strand::Forward, location: 2..11, protein: "MK".to_string()}]);
+/// ```
+pub fn find_orfs(dna: &str, options: &OrfOptions) -> RosalindResult<Vec<Orf>> {
+  let forward: String = dna.chars().filter(|&nucleotide| nucleotide != '\n').collect::<String>().to_ascii_uppercase();
+  let reverse = try!(reverse_complement_dna_iupac(&forward.replace('U', "T")));
+  let len = forward.len();
+
+  let mut orfs: Vec<Orf> = try!(find_strand_orfs(&forward.replace('U', "T"), options))
+    .into_iter()
+    .map(|(location, protein)| Orf {strand: Strand::Forward, location, protein})
+    .collect();
+
+  let mut reverse_orfs: Vec<Orf> = try!(find_strand_orfs(&reverse, options))
+    .into_iter()
+    .map(|(location, protein)| Orf {strand: Strand::Reverse, location: len - location.end..len - location.start, protein})
+    .collect();
+  reverse_orfs.sort_by_key(|orf| (orf.location.start, orf.location.end));
+
+  orfs.extend(reverse_orfs);
+  Ok(orfs)
+}
+
+/// This function returns distinct candidate proteins translated from open reading
+/// frames of DNA string in order of `find_orfs`
+///
+/// ## Examples
+/// ```
+/// use rosalind::orf::*;
+///
+/// let dna = "AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG";
+/// let proteins = orf_proteins(dna, &OrfOptions::default()).unwrap();
+/// assert_eq!(proteins, vec!["M", "MGMTPRLGLESLLE", "MTPRLGLESLLE", "MLLGSFRLIPKETLIQVAGSSPCNLS"]);
+/// ```
+pub fn orf_proteins(dna: &str, options: &OrfOptions) -> RosalindResult<Vec<String>> {
+  let mut proteins: Vec<String> = Vec::new();
+  for orf in try!(find_orfs(dna, options)) {
+    if !proteins.contains(&orf.protein) { proteins.push(orf.protein); }
+  }
+  Ok(proteins)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use prot::{GeneticCode, Strand};
+  use super::super::RosalindError::UnknownNucleotide;
+
+  const DNA: &'static str =
+    "AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG";
+
+  #[test]
+  fn it_should_return_orf_proteins() {
+    let mut proteins = orf_proteins(DNA, &OrfOptions::default()).unwrap();
+    proteins.sort();
+    assert_eq!(proteins, vec!["M", "MGMTPRLGLESLLE", "MLLGSFRLIPKETLIQVAGSSPCNLS", "MTPRLGLESLLE"]);
+  }
+
+  #[test]
+  fn it_should_return_orf_locations() {
+    let orfs = find_orfs(DNA, &OrfOptions::default()).unwrap();
+    let locations: Vec<(Strand, usize, usize)> = orfs.iter().map(|orf| (orf.strand, orf.location.start, orf.location.end)).collect();
+    assert_eq!(locations, vec![
+      (Strand::Forward, 4, 10),
+      (Strand::Forward, 24, 69),
+      (Strand::Forward, 30, 69),
+      (Strand::Reverse, 10, 91),
+      (Strand::Reverse, 20, 26),
+    ]);
+    assert_eq!(&DNA[4..10], "ATGTAG");
+    assert_eq!(::revc::reverse_complement_dna(&DNA[20..26]).unwrap(), "ATGTAA");
+  }
+
+  #[test]
+  fn it_should_skip_nested_orfs() {
+    let options = OrfOptions {nested: false, ..OrfOptions::default()};
+    let proteins = orf_proteins(DNA, &options).unwrap();
+    assert_eq!(proteins, vec!["M", "MGMTPRLGLESLLE", "MLLGSFRLIPKETLIQVAGSSPCNLS"]);
+  }
+
+  #[test]
+  fn it_should_skip_short_orfs() {
+    let options = OrfOptions {min_length: 13, ..OrfOptions::default()};
+    let proteins = orf_proteins(DNA, &options).unwrap();
+    assert_eq!(proteins, vec!["MGMTPRLGLESLLE", "MLLGSFRLIPKETLIQVAGSSPCNLS"]);
+  }
+
+  #[test]
+  fn it_should_ignore_orfs_without_stop_codon() {
+    assert_eq!(find_orfs("ATGAAAAAA", &OrfOptions::default()).unwrap(), vec![]);
+    assert_eq!(find_orfs("", &OrfOptions::default()).unwrap(), vec![]);
+  }
+
+  #[test]
+  fn it_should_find_orfs_in_lowercase_dna() {
+    assert_eq!(orf_proteins(&DNA.to_lowercase(), &OrfOptions::default()).unwrap().len(), 4);
+  }
+
+  #[test]
+  fn it_should_find_orfs_with_alternative_genetic_code() {
+    let options = OrfOptions {code: GeneticCode::new(2).unwrap(), ..OrfOptions::default()};
+    assert_eq!(orf_proteins("ATGTGAAGG", &options).unwrap(), vec!["MW"]);
+  }
+
+  #[test]
+  fn it_should_find_orfs_with_alternative_start_codons() {
+    let options = OrfOptions {code: GeneticCode::new(2).unwrap(), ..OrfOptions::default()};
+    assert_eq!(orf_proteins("ATTAAATAG", &options).unwrap(), Vec::<String>::new());
+
+    let options = OrfOptions {alternative_starts: true, ..options};
+    assert_eq!(orf_proteins("ATTAAATAG", &options).unwrap(), vec!["MK", "M"]);
+    assert_eq!(orf_proteins("NTGAAATAG", &options).unwrap(), Vec::<String>::new());
+
+    let options = OrfOptions {alternative_starts: true, ..OrfOptions::default()};
+    assert_eq!(orf_proteins("CTGTTGAAATAA", &options).unwrap(), vec!["MLK", "MK"]);
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(find_orfs("ATGZ", &OrfOptions::default()).unwrap_err(), UnknownNucleotide('Z'));
+  }
+}
@@ -0,0 +1,457 @@
+//! Module for DNA strings packed into 2 bits per nucleotide
+
+use std::cmp;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+use RosalindResult;
+use RosalindError;
+use RosalindError::{UnknownNucleotide, HammingStringsLengthError};
+use seq::{DnaBase, DnaSeq};
+
+const NUCLEOTIDES_PER_WORD: usize = 32;
+const LOW_BITS: u64 = 0x5555_5555_5555_5555;
+const NUCLEOTIDE_SYMBOLS: [char; 4] = ['A', 'C', 'G', 'T'];
+
+fn encode(nucleotide: char) -> RosalindResult<Option<u64>> {
+  match nucleotide.to_ascii_uppercase() {
+    'A' => Ok(Some(0)),
+    'C' => Ok(Some(1)),
+    'G' => Ok(Some(2)),
+    'T' => Ok(Some(3)),
+    'N' => Ok(None),
+    _ => Err(UnknownNucleotide(nucleotide)),
+  }
+}
+
+fn low_bits_mask(nucleotides: usize) -> u64 {
+  if nucleotides >= NUCLEOTIDES_PER_WORD { !0 } else { (1u64 << (2 * nucleotides)) - 1 }
+}
+
+// reverses order of 2-bit nucleotides in the word
+fn reverse_word(word: u64) -> u64 {
+  let bits = word.reverse_bits();
+  ((bits >> 1) & LOW_BITS) | ((bits & LOW_BITS) << 1)
+}
+
+// takes `len` nucleotides starting from `start` into a new vector of words
+fn extract_words(words: &[u64], start: usize, len: usize) -> Vec<u64> {
+  let (offset, shift) = (start / NUCLEOTIDES_PER_WORD, 2 * (start % NUCLEOTIDES_PER_WORD));
+  let mut extracted: Vec<u64> = (0..(len + NUCLEOTIDES_PER_WORD - 1) / NUCLEOTIDES_PER_WORD).map(|i| {
+    let low = words[offset + i] >> shift;
+    let high = if shift > 0 && offset + i + 1 < words.len() { words[offset + i + 1] << (64 - shift) } else { 0 };
+    low | high
+  }).collect();
+
+  if let Some(last) = extracted.last_mut() {
+    *last &= low_bits_mask(len - (len - 1) / NUCLEOTIDES_PER_WORD * NUCLEOTIDES_PER_WORD);
+  }
+  extracted
+}
+
+/// This function decodes k-mer code returned by `PackedDna::kmer` into DNA string
+///
+/// ## Examples
+/// ```
+/// use rosalind::packed::*;
+///
+/// let dna = PackedDna::new("GATTACA").unwrap();
+/// assert_eq!(decode_kmer(dna.kmer(1, 4).unwrap(), 4), "ATTA");
+/// ```
+pub fn decode_kmer(code: u64, k: usize) -> String {
+  (0..cmp::min(k, NUCLEOTIDES_PER_WORD))
+    .map(|i| NUCLEOTIDE_SYMBOLS[((code >> (2 * i)) & 3) as usize])
+    .collect()
+}
+
+/// This structure contains DNA string packed into 2 bits per nucleotide, runs
+/// of unknown nucleotides `N` are kept in a separate table
+///
+/// ## Examples
+/// ```
+/// use rosalind::packed::*;
+///
+/// let dna = PackedDna::new("AAAACCCGGTNNNA").unwrap();
+/// assert_eq!(dna.len(), 14);
+/// assert_eq!(dna.get(4), Some('C'));
+/// assert_eq!(dna.get(10), Some('N'));
+/// assert_eq!(dna.slice(4, 12).unwrap().to_string(), "CCCGGTNN");
+/// assert_eq!(dna.reverse_complement().to_string(), "TNNNACCGGGTTTT");
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct PackedDna {
+  words: Vec<u64>,
+  len: usize,
+  n_runs: Vec<Range<usize>>,
+}
+
+impl PackedDna {
+  /// This function packs DNA string, nucleotides are case-insensitive,
+  /// `N` denotes unknown nucleotide and `\n` symbols are skipped
+  pub fn new(dna: &str) -> RosalindResult<PackedDna> {
+    let mut packed_dna = PackedDna::with_capacity(dna.len());
+    for nucleotide in dna.chars().filter(|&nucleotide| nucleotide != '\n') {
+      try!(packed_dna.push(nucleotide));
+    }
+    Ok(packed_dna)
+  }
+
+  /// This function creates empty packed DNA string with space for `capacity` nucleotides
+  pub fn with_capacity(capacity: usize) -> PackedDna {
+    PackedDna {
+      words: Vec::with_capacity((capacity + NUCLEOTIDES_PER_WORD - 1) / NUCLEOTIDES_PER_WORD),
+      len: 0,
+      n_runs: Vec::new(),
+    }
+  }
+
+  /// This function appends nucleotide to the end of packed DNA string
+  pub fn push(&mut self, nucleotide: char) -> RosalindResult<()> {
+    let code = try!(encode(nucleotide));
+    if self.len % NUCLEOTIDES_PER_WORD == 0 { self.words.push(0); }
+
+    match code {
+      Some(code) => {
+        *self.words.last_mut().unwrap() |= code << (2 * (self.len % NUCLEOTIDES_PER_WORD));
+      },
+      None => {
+        let len = self.len;
+        match self.n_runs.last_mut() {
+          Some(ref mut run) if run.end == len => run.end += 1,
+          _ => self.n_runs.push(len..len + 1),
+        }
+      },
+    }
+
+    self.len += 1;
+    Ok(())
+  }
+
+  /// This function returns length of DNA string
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// This function checks whether DNA string is empty
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// This function returns runs of unknown nucleotides `N` as zero-based half-open ranges
+  pub fn n_runs(&self) -> &[Range<usize>] {
+    &self.n_runs
+  }
+
+  fn code(&self, i: usize) -> u64 {
+    (self.words[i / NUCLEOTIDES_PER_WORD] >> (2 * (i % NUCLEOTIDES_PER_WORD))) & 3
+  }
+
+  fn has_n(&self, start: usize, end: usize) -> bool {
+    let i = self.n_runs.partition_point(|run| run.end <= start);
+    i < self.n_runs.len() && self.n_runs[i].start < end
+  }
+
+  /// This function returns nucleotide at position `i` (zero-based)
+  pub fn get(&self, i: usize) -> Option<char> {
+    if i >= self.len { return None; }
+    if self.has_n(i, i + 1) { return Some('N'); }
+    Some(NUCLEOTIDE_SYMBOLS[self.code(i) as usize])
+  }
+
+  /// This function returns packed substring of nucleotides from `start` to `end`
+  /// (zero-based, `end` is excluded), nucleotides are copied word by word
+  pub fn slice(&self, start: usize, end: usize) -> Option<PackedDna> {
+    if start > end || end > self.len { return None; }
+
+    let n_runs = self.n_runs
+      .iter()
+      .filter(|run| run.start < end && run.end > start)
+      .map(|run| cmp::max(run.start, start) - start..cmp::min(run.end, end) - start)
+      .collect();
+
+    Some(PackedDna {words: extract_words(&self.words, start, end - start), len: end - start, n_runs})
+  }
+
+  /// This function returns iterator over nucleotides
+  pub fn iter(&self) -> Iter<'_> {
+    Iter {dna: self, position: 0}
+  }
+
+  /// This function returns reverse complement of packed DNA string,
+  /// nucleotides are complemented and reversed word by word
+  pub fn reverse_complement(&self) -> PackedDna {
+    let reversed: Vec<u64> = self.words.iter().rev().map(|&word| reverse_word(!word)).collect();
+    let padding = self.words.len() * NUCLEOTIDES_PER_WORD - self.len;
+
+    let mut complement_dna = PackedDna {
+      words: extract_words(&reversed, padding, self.len),
+      len: self.len,
+      n_runs: self.n_runs.iter().rev().map(|run| self.len - run.end..self.len - run.start).collect(),
+    };
+    complement_dna.clear_n_runs();
+    complement_dna
+  }
+
+  // keeps bits of unknown nucleotides zeroed, so equal strings have equal words
+  fn clear_n_runs(&mut self) {
+    for run in self.n_runs.iter() {
+      let mut i = run.start;
+      while i < run.end {
+        let offset = i % NUCLEOTIDES_PER_WORD;
+        let count = cmp::min(run.end - i, NUCLEOTIDES_PER_WORD - offset);
+        self.words[i / NUCLEOTIDES_PER_WORD] &= !(low_bits_mask(count) << (2 * offset));
+        i += count;
+      }
+    }
+  }
+
+  /// This function returns code of k-mer (`k` is up to 32) starting at position `i`,
+  /// first nucleotide of the k-mer is stored in the lowest bits. K-mers which
+  /// contain unknown nucleotides do not have codes.
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::packed::*;
+  ///
+  /// let dna = PackedDna::new("ACGTNA").unwrap();
+  /// assert_eq!(dna.kmer(0, 2), Some(0b0100));
+  /// assert_eq!(dna.kmer(2, 3), None);
+  /// assert_eq!(dna.kmer(5, 2), None);
+  /// ```
+  pub fn kmer(&self, i: usize, k: usize) -> Option<u64> {
+    if k == 0 || k > NUCLEOTIDES_PER_WORD || i + k > self.len || self.has_n(i, i + k) { return None; }
+    Some(extract_words(&self.words[i / NUCLEOTIDES_PER_WORD..], i % NUCLEOTIDES_PER_WORD, k)[0])
+  }
+
+  /// This function returns iterator over positions and codes of all k-mers
+  /// without unknown nucleotides
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::packed::*;
+  ///
+  /// let dna = PackedDna::new("GATNACA").unwrap();
+  /// let kmers: Vec<(usize, String)> = dna.kmers(2).map(|(i, code)| (i, decode_kmer(code, 2))).collect();
+  /// assert_eq!(kmers, vec![(0, "GA".to_string()), (1, "AT".to_string()), (4, "AC".to_string()), (5, "CA".to_string())]);
+  /// ```
+  pub fn kmers(&self, k: usize) -> Kmers<'_> {
+    Kmers {dna: self, k, position: 0}
+  }
+
+  /// This function calculates Hamming distance between packed DNA strings word by word,
+  /// unknown nucleotide `N` is only equal to another unknown nucleotide
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::RosalindError::HammingStringsLengthError;
+  /// use rosalind::packed::*;
+  ///
+  /// let s = PackedDna::new("GAGCCTACTAACGGGAT").unwrap();
+  /// let t = PackedDna::new("CATCGTAATGACGGCCT").unwrap();
+  /// assert_eq!(s.hamming_distance(&t).unwrap(), 7);
+  /// assert_eq!(s.hamming_distance(&PackedDna::new("G").unwrap()).unwrap_err(), HammingStringsLengthError);
+  /// ```
+  pub fn hamming_distance(&self, other: &PackedDna) -> RosalindResult<usize> {
+    if self.len != other.len { return Err(HammingStringsLengthError); }
+
+    let mut distance: usize = self.words.iter().zip(other.words.iter()).map(|(s, t)| {
+      let bits = s ^ t;
+      ((bits | bits >> 1) & LOW_BITS).count_ones() as usize
+    }).sum();
+
+    // unknown nucleotides are packed as `A`, so only mismatches of `N` and `A` are missed
+    for &(dna, other_dna) in [(self, other), (other, self)].iter() {
+      for run in dna.n_runs.iter() {
+        distance += run.clone().filter(|&i| other_dna.code(i) == 0 && !other_dna.has_n(i, i + 1)).count();
+      }
+    }
+
+    Ok(distance)
+  }
+}
+
+impl<'a> From<&'a DnaSeq> for PackedDna {
+  fn from(dna: &'a DnaSeq) -> PackedDna {
+    let mut packed_dna = PackedDna::with_capacity(dna.len());
+    for nucleotide in dna.bases() {
+      packed_dna.push(DnaBase::to_char(nucleotide)).unwrap();
+    }
+    packed_dna
+  }
+}
+
+impl FromStr for PackedDna {
+  type Err = RosalindError;
+
+  fn from_str(dna: &str) -> RosalindResult<PackedDna> {
+    PackedDna::new(dna)
+  }
+}
+
+impl fmt::Display for PackedDna {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let dna: String = self.iter().collect();
+    write!(f, "{}", dna)
+  }
+}
+
+/// This structure iterates over nucleotides of packed DNA string
+pub struct Iter<'a> {
+  dna: &'a PackedDna,
+  position: usize,
+}
+
+impl<'a> Iterator for Iter<'a> {
+  type Item = char;
+
+  fn next(&mut self) -> Option<char> {
+    let nucleotide = self.dna.get(self.position);
+    if nucleotide.is_some() { self.position += 1; }
+    nucleotide
+  }
+}
+
+/// This structure iterates over k-mers of packed DNA string
+pub struct Kmers<'a> {
+  dna: &'a PackedDna,
+  k: usize,
+  position: usize,
+}
+
+impl<'a> Iterator for Kmers<'a> {
+  type Item = (usize, u64);
+
+  fn next(&mut self) -> Option<(usize, u64)> {
+    if self.k == 0 || self.k > NUCLEOTIDES_PER_WORD { return None; }
+
+    while self.position + self.k <= self.dna.len {
+      let position = self.position;
+      self.position += 1;
+      if let Some(code) = self.dna.kmer(position, self.k) { return Some((position, code)); }
+    }
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use seq::DnaSeq;
+  use super::super::RosalindError::{UnknownNucleotide, HammingStringsLengthError};
+
+  fn long_dna() -> String {
+    "GATTACAnnnCCGGTTAACGTNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNACGTTGCAAGGCTTACGATCGATCGGCTAGCTAGCTNACGGCATCGACT"
+      .to_string()
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(PackedDna::new("ACGZ").unwrap_err(), UnknownNucleotide('Z'));
+    assert_eq!(PackedDna::new("ACGR").unwrap_err(), UnknownNucleotide('R'));
+  }
+
+  #[test]
+  fn it_should_convert_to_and_from_string() {
+    let dna = long_dna();
+    let packed_dna = PackedDna::new(&dna).unwrap();
+    assert_eq!(packed_dna.len(), dna.len());
+    assert_eq!(packed_dna.to_string(), dna.to_uppercase());
+    assert_eq!(packed_dna.n_runs(), &[7..10, 21..57, 93..94]);
+    assert_eq!(PackedDna::new("AC\nGT\n").unwrap().to_string(), "ACGT");
+    assert!(PackedDna::new("").unwrap().is_empty());
+  }
+
+  #[test]
+  fn it_should_convert_from_dna_seq() {
+    let dna = DnaSeq::new("GATTACA").unwrap();
+    assert_eq!(PackedDna::from(&dna), PackedDna::new("GATTACA").unwrap());
+  }
+
+  #[test]
+  fn it_should_index_nucleotides() {
+    let dna = long_dna().to_uppercase();
+    let packed_dna = PackedDna::new(&dna).unwrap();
+    for (i, nucleotide) in dna.chars().enumerate() {
+      assert_eq!(packed_dna.get(i), Some(nucleotide));
+    }
+    assert_eq!(packed_dna.get(dna.len()), None);
+    assert_eq!(packed_dna.iter().collect::<String>(), dna);
+  }
+
+  #[test]
+  fn it_should_slice_nucleotides() {
+    let dna = long_dna().to_uppercase();
+    let packed_dna = PackedDna::new(&dna).unwrap();
+    for &(start, end) in [(0, 0), (0, 10), (5, 40), (31, 33), (32, 64), (33, 101), (0, dna.len())].iter() {
+      let slice = packed_dna.slice(start, end).unwrap();
+      assert_eq!(slice.to_string(), &dna[start..end]);
+      assert_eq!(slice, PackedDna::new(&dna[start..end]).unwrap());
+    }
+    assert_eq!(packed_dna.slice(5, 4), None);
+    assert_eq!(packed_dna.slice(0, dna.len() + 1), None);
+  }
+
+  #[test]
+  fn it_should_reverse_complement_dna() {
+    let dna = long_dna();
+    let complement_dna = ::revc::reverse_complement_dna_iupac(&dna).unwrap();
+    let packed_dna = PackedDna::new(&dna).unwrap();
+    assert_eq!(packed_dna.reverse_complement(), PackedDna::new(&complement_dna).unwrap());
+    assert_eq!(PackedDna::new("AAAACCCGGT").unwrap().reverse_complement().to_string(), "ACCGGGTTTT");
+  }
+
+  #[test]
+  fn it_should_extract_kmers() {
+    let dna = long_dna().to_uppercase();
+    let packed_dna = PackedDna::new(&dna).unwrap();
+    for k in vec![1, 5, 31, 32] {
+      for i in 0..(dna.len() - k + 1) {
+        let kmer = &dna[i..i + k];
+        let code = packed_dna.kmer(i, k);
+        if kmer.contains('N') {
+          assert_eq!(code, None);
+        } else {
+          assert_eq!(decode_kmer(code.unwrap(), k), kmer);
+        }
+      }
+    }
+    assert_eq!(packed_dna.kmer(0, 33), None);
+    assert_eq!(packed_dna.kmer(0, 0), None);
+  }
+
+  #[test]
+  fn it_should_iterate_kmers() {
+    let packed_dna = PackedDna::new("ACGTNNAC").unwrap();
+    let kmers: Vec<(usize, String)> = packed_dna.kmers(3).map(|(i, code)| (i, decode_kmer(code, 3))).collect();
+    assert_eq!(kmers, vec![(0, "ACG".to_string()), (1, "CGT".to_string())]);
+  }
+
+  #[test]
+  fn it_should_return_hamming_distance() {
+    let s = PackedDna::new("GAGCCTACTAACGGGAT").unwrap();
+    let t = PackedDna::new("CATCGTAATGACGGCCT").unwrap();
+    assert_eq!(s.hamming_distance(&t).unwrap(), 7);
+  }
+
+  #[test]
+  fn it_should_return_hamming_distance_with_unknown_nucleotides() {
+    let s = PackedDna::new("NNAACCNNGT").unwrap();
+    let t = PackedDna::new("ANNACCNAGN").unwrap();
+    assert_eq!(s.hamming_distance(&t).unwrap(), 4);
+  }
+
+  #[test]
+  fn it_should_return_hamming_distance_of_long_dna() {
+    let s = long_dna().to_uppercase();
+    let t = ::revc::reverse_complement_dna_iupac(&s).unwrap();
+    let expected = s.chars().zip(t.chars()).filter(|&(a, b)| a != b).count();
+    let (s, t) = (PackedDna::new(&s).unwrap(), PackedDna::new(&t).unwrap());
+    assert_eq!(s.hamming_distance(&t).unwrap(), expected);
+  }
+
+  #[test]
+  fn it_should_return_error_when_strings_have_different_length() {
+    let (s, t) = (PackedDna::new("ACGT").unwrap(), PackedDna::new("ACG").unwrap());
+    assert_eq!(s.hamming_distance(&t).unwrap_err(), HammingStringsLengthError);
+  }
+}
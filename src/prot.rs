@@ -1,38 +1,233 @@
 //! Module for `Translating RNA into Protein, Inferring mRNA from Protein, Calculating Protein Mass`
 
 use RosalindResult;
-use RosalindError::{CodonParseError, UnknownCodon, UnknownAminoAcid};
-use constants::CODON_STOP_SYMBOL;
-
-fn codon_into_amino_acid(codon: &str) -> RosalindResult<char> {
-  match codon {
-    "GCU" | "GCC" | "GCA" | "GCG" => Ok('A'),
-    "UGU" | "UGC" => Ok('C'),
-    "GAU" | "GAC" => Ok('D'),
-    "GAA" | "GAG" => Ok('E'),
-    "UUU" | "UUC" => Ok('F'),
-    "GGU" | "GGC" | "GGA" | "GGG" => Ok('G'),
-    "CAU" | "CAC" => Ok('H'),
-    "AUU" | "AUC" | "AUA" => Ok('I'),
-    "AAA" | "AAG" => Ok('K'),
-    "UUA" | "UUG" | "CUU" | "CUC" | "CUA" | "CUG" => Ok('L'),
-    "AUG" => Ok('M'),
-    "AAU" | "AAC" => Ok('N'),
-    "CCU" | "CCC" | "CCA" | "CCG" => Ok('P'),
-    "CAA" | "CAG" => Ok('Q'),
-    "CGU" | "CGC" | "CGA" | "CGG" | "AGA" | "AGG" => Ok('R'),
-    "UCU" | "UCC" | "UCA" | "UCG" | "AGU" | "AGC" => Ok('S'),
-    "ACU" | "ACC" | "ACA" | "ACG" => Ok('T'),
-    "GUU" | "GUC" | "GUA" | "GUG" => Ok('V'),
-    "UGG" => Ok('W'),
-    "UAU" | "UAC" => Ok('Y'),
-    "UAA" | "UAG" | "UGA" => Ok(CODON_STOP_SYMBOL),
-    _ => Err(UnknownCodon(codon.to_string())),
+use RosalindError::{CodonParseError, UnknownCodon, UnknownAminoAcid, UnknownGeneticCode};
+use rna::transcribe_dna_into_rna;
+use revc::reverse_complement_dna_iupac;
+use iupac;
+use constants::{CODON_STOP_SYMBOL, PROTEIN_STOP_SYMBOL, UNKNOWN_AMINO_ACID_SYMBOL};
+
+// NCBI translation tables: identifier, name, amino acids and start codons (`M`)
+// of codons ordered by bases `U`, `C`, `A`, `G`, stop codons are denoted by `*`
+const GENETIC_CODES: [(u8, &str, &str, &str); 27] = [
+  (1, "Standard",
+    "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "---M---------------M---------------M----------------------------"),
+  (2, "Vertebrate Mitochondrial",
+    "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG",
+    "--------------------------------MMMM---------------M------------"),
+  (3, "Yeast Mitochondrial",
+    "FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "----------------------------------MM---------------M------------"),
+  (4, "Mold, Protozoan, and Coelenterate Mitochondrial and Mycoplasma/Spiroplasma",
+    "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "--MM---------------M------------MMMM---------------M------------"),
+  (5, "Invertebrate Mitochondrial",
+    "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG",
+    "---M----------------------------MMMM---------------M------------"),
+  (6, "Ciliate, Dasycladacean and Hexamita Nuclear",
+    "FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (9, "Echinoderm and Flatworm Mitochondrial",
+    "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+    "-----------------------------------M---------------M------------"),
+  (10, "Euplotid Nuclear",
+    "FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (11, "Bacterial, Archaeal and Plant Plastid",
+    "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "---M---------------M------------MMMM---------------M------------"),
+  (12, "Alternative Yeast Nuclear",
+    "FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-------------------M---------------M----------------------------"),
+  (13, "Ascidian Mitochondrial",
+    "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG",
+    "---M------------------------------MM---------------M------------"),
+  (14, "Alternative Flatworm Mitochondrial",
+    "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (15, "Blepharisma Nuclear",
+    "FFLLSSSSYY*QCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (16, "Chlorophycean Mitochondrial",
+    "FFLLSSSSYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (21, "Trematode Mitochondrial",
+    "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+    "-----------------------------------M---------------M------------"),
+  (22, "Scenedesmus obliquus Mitochondrial",
+    "FFLLSS*SYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (23, "Thraustochytrium Mitochondrial",
+    "FF*LSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "--------------------------------M--M---------------M------------"),
+  (24, "Rhabdopleuridae Mitochondrial",
+    "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+    "---M---------------M---------------M---------------M------------"),
+  (25, "Candidate Division SR1 and Gracilibacteria",
+    "FFLLSSSSYY**CCGWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "---M-------------------------------M---------------M------------"),
+  (26, "Pachysolen tannophilus Nuclear",
+    "FFLLSSSSYY**CC*WLLLAPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-------------------M---------------M----------------------------"),
+  (27, "Karyorelict Nuclear",
+    "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (28, "Condylostoma Nuclear",
+    "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (29, "Mesodinium Nuclear",
+    "FFLLSSSSYYYYCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (30, "Peritrich Nuclear",
+    "FFLLSSSSYYEECC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (31, "Blastocrithidia Nuclear",
+    "FFLLSSSSYYEECCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "-----------------------------------M----------------------------"),
+  (32, "Balanophoraceae Plastid",
+    "FFLLSSSSYY*WCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+    "---M---------------M------------MMMM---------------M------------"),
+  (33, "Cephalodiscidae Mitochondrial",
+    "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+    "---M---------------M---------------M---------------M------------"),
+];
+
+const RNA_CODON_BASES: [char; 4] = ['U', 'C', 'A', 'G'];
+const RNA_CODON_BASE_MASKS: [u8; 4] = [0b1000, 0b0010, 0b0001, 0b0100];
+
+fn codon_index(codon: &str) -> RosalindResult<usize> {
+  let bases: Vec<char> = codon.chars().collect();
+  if bases.len() != 3 { return Err(UnknownCodon(codon.to_string())); }
+
+  let mut index = 0;
+  for base in bases {
+    let base_index = try!(RNA_CODON_BASES
+      .iter()
+      .position(|&b| b == base.to_ascii_uppercase())
+      .ok_or_else(|| UnknownCodon(codon.to_string())));
+    index = index * 4 + base_index;
+  }
+  Ok(index)
+}
+
+fn codon_from_index(index: usize) -> String {
+  vec![index / 16, index / 4 % 4, index % 4].into_iter().map(|i| RNA_CODON_BASES[i]).collect()
+}
+
+/// This structure represents genetic code defined by NCBI translation table
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::UnknownGeneticCode;
+/// use rosalind::constants::CODON_STOP_SYMBOL;
+/// use rosalind::prot::*;
+///
+/// let code = GeneticCode::new(2).unwrap();
+/// assert_eq!(code.name(), "Vertebrate Mitochondrial");
+/// assert_eq!(code.amino_acid("UGA").unwrap(), 'W');
+/// assert_eq!(code.amino_acid("AGA").unwrap(), CODON_STOP_SYMBOL);
+/// assert_eq!(code.is_start_codon("AUA").unwrap(), true);
+/// assert_eq!(GeneticCode::new(7).unwrap_err(), UnknownGeneticCode(7));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct GeneticCode {
+  id: u8,
+  name: &'static str,
+  amino_acids: &'static [u8],
+  start_codons: &'static [u8],
+}
+
+impl GeneticCode {
+  /// This function returns genetic code with NCBI translation table identifier `id`
+  pub fn new(id: u8) -> RosalindResult<GeneticCode> {
+    GENETIC_CODES
+      .iter()
+      .find(|code| code.0 == id)
+      .map(|&(id, name, amino_acids, start_codons)| GeneticCode {
+        id,
+        name,
+        amino_acids: amino_acids.as_bytes(),
+        start_codons: start_codons.as_bytes(),
+      })
+      .ok_or(UnknownGeneticCode(id))
+  }
+
+  /// This function returns the standard genetic code (NCBI translation table 1)
+  pub fn standard() -> GeneticCode {
+    GeneticCode::new(1).unwrap()
+  }
+
+  /// This function returns all NCBI translation tables ordered by identifier
+  pub fn all() -> Vec<GeneticCode> {
+    GENETIC_CODES.iter().map(|code| GeneticCode::new(code.0).unwrap()).collect()
+  }
+
+  /// This function returns NCBI translation table identifier
+  pub fn id(&self) -> u8 {
+    self.id
+  }
+
+  /// This function returns NCBI translation table name
+  pub fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// This function translates RNA codon into amino acid, stop codons are
+  /// translated into `CODON_STOP_SYMBOL`, codons are case-insensitive
+  pub fn amino_acid(&self, codon: &str) -> RosalindResult<char> {
+    match self.amino_acids[try!(codon_index(codon))] {
+      b'*' => Ok(CODON_STOP_SYMBOL),
+      amino_acid => Ok(amino_acid as char),
+    }
+  }
+
+  /// This function checks whether RNA codon may initiate translation
+  pub fn is_start_codon(&self, codon: &str) -> RosalindResult<bool> {
+    Ok(self.start_codons[try!(codon_index(codon))] == b'M')
+  }
+
+  /// This function checks whether RNA codon terminates translation
+  pub fn is_stop_codon(&self, codon: &str) -> RosalindResult<bool> {
+    Ok(try!(self.amino_acid(codon)) == CODON_STOP_SYMBOL)
+  }
+
+  /// This function returns RNA codons which may initiate translation
+  pub fn start_codons(&self) -> Vec<String> {
+    (0..64).filter(|&i| self.start_codons[i] == b'M').map(codon_from_index).collect()
+  }
+
+  /// This function returns RNA codons which terminate translation
+  pub fn stop_codons(&self) -> Vec<String> {
+    (0..64).filter(|&i| self.amino_acids[i] == b'*').map(codon_from_index).collect()
+  }
+
+  // translates DNA or RNA codon with IUPAC codes, stop codons are translated into `*`
+  // and codons which may encode different amino acids into `X`
+  fn translate_codon(&self, codon: &[char]) -> RosalindResult<char> {
+    let mut masks = [0u8; 3];
+    for (mask, &nucleotide) in masks.iter_mut().zip(codon.iter()) {
+      *mask = try!(iupac::nucleotide_mask(nucleotide));
+    }
+
+    let mut amino_acids = (0..64).filter(|&index| {
+      (0..3).all(|i| masks[i] & RNA_CODON_BASE_MASKS[index >> (2 * (2 - i)) & 3] != 0)
+    }).map(|index| self.amino_acids[index]);
+
+    let amino_acid = amino_acids.next().unwrap();
+    if amino_acids.any(|other| other != amino_acid) { return Ok(UNKNOWN_AMINO_ACID_SYMBOL); }
+    if amino_acid == b'*' { Ok(PROTEIN_STOP_SYMBOL) } else { Ok(amino_acid as char) }
+  }
+}
+
+impl Default for GeneticCode {
+  fn default() -> GeneticCode {
+    GeneticCode::standard()
   }
 }
 
 fn amino_acid_into_codon<'a>(amino_acid: char) -> RosalindResult<Vec<&'a str>> {
-  match amino_acid {
+  match amino_acid.to_ascii_uppercase() {
     'A' => Ok(vec!["GCU", "GCC", "GCA", "GCG"]),
     'C' => Ok(vec!["UGU", "UGC"]),
     'D' => Ok(vec!["GAU", "GAC"]),
@@ -59,7 +254,7 @@ fn amino_acid_into_codon<'a>(amino_acid: char) -> RosalindResult<Vec<&'a str>> {
 }
 
 fn get_monoisotopic_mass(amino_acid: char) -> RosalindResult<f64> {
-  match amino_acid {
+  match amino_acid.to_ascii_uppercase() {
     'A' => Ok(71.03711f64),
     'C' => Ok(103.00919f64),
     'D' => Ok(115.02694f64),
@@ -85,7 +280,8 @@ fn get_monoisotopic_mass(amino_acid: char) -> RosalindResult<f64> {
   }
 }
 
-/// This function translates provided RNA string into protein string
+/// This function translates provided RNA string into protein string using the standard
+/// genetic code, codons are case-insensitive
 ///
 /// ## Examples
 /// ```
@@ -95,10 +291,27 @@ fn get_monoisotopic_mass(amino_acid: char) -> RosalindResult<f64> {
 /// let rna = "AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA";
 /// assert_eq!(translate_rna_into_protein(rna).unwrap(), "MAMAPRTEINSTRING");
 /// assert_eq!(translate_rna_into_protein("AUGUGA\n").unwrap(), "M");
+/// assert_eq!(translate_rna_into_protein("augGCCuga").unwrap(), "MA");
 /// assert_eq!(translate_rna_into_protein("Z").unwrap_err(), CodonParseError);
 /// assert_eq!(translate_rna_into_protein("ZZZ").unwrap_err(), UnknownCodon("ZZZ".to_string()));
 /// ```
 pub fn translate_rna_into_protein(rna: &str) -> RosalindResult<String> {
+  translate_rna_into_protein_with_code(rna, &GeneticCode::standard())
+}
+
+/// This function translates provided RNA string into protein string using genetic code
+/// `code`, translation stops at the first stop codon
+///
+/// ## Examples
+/// ```
+/// use rosalind::prot::*;
+///
+/// let rna = "AUGAGAUGGUGA";
+/// assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::standard()).unwrap(), "MRW");
+/// assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::new(2).unwrap()).unwrap(), "M");
+/// assert_eq!(translate_rna_into_protein_with_code("UGAUAA", &GeneticCode::new(4).unwrap()).unwrap(), "W");
+/// ```
+pub fn translate_rna_into_protein_with_code(rna: &str, code: &GeneticCode) -> RosalindResult<String> {
   let mut rna_len = rna.len();
   if rna.ends_with("\n") { rna_len = rna_len - 1; }
   if rna_len % 3 != 0 { return Err(CodonParseError); }
@@ -106,7 +319,7 @@ pub fn translate_rna_into_protein(rna: &str) -> RosalindResult<String> {
   let mut prot = String::new();
   for i in 0..(rna_len / 3) {
     let (left, right) = (i * 3, (i + 1) * 3);
-    let amino_acid = try!(codon_into_amino_acid(&rna[left..right]));
+    let amino_acid = try!(code.amino_acid(&rna[left..right]));
     if amino_acid == CODON_STOP_SYMBOL { break; }
     prot.push(amino_acid);
   }
@@ -114,6 +327,94 @@ pub fn translate_rna_into_protein(rna: &str) -> RosalindResult<String> {
   Ok(prot)
 }
 
+/// This function returns identifiers of NCBI translation tables which translate
+/// DNA string into protein string (Rosalind problem `Protein Translation`)
+///
+/// ## Examples
+/// ```
+/// use rosalind::prot::*;
+///
+/// let dna = "ATGGCCATGGCGCCCAGAACTGAGATCAATAGTACCCGTATTAACGGGTGA";
+/// let codes = find_genetic_codes(dna, "MAMAPRTEINSTRING").unwrap();
+/// assert_eq!(codes, vec![1, 6, 11, 12, 15, 16, 22, 23, 26, 29, 30, 32]);
+/// ```
+pub fn find_genetic_codes(dna: &str, protein: &str) -> RosalindResult<Vec<u8>> {
+  let rna = try!(transcribe_dna_into_rna(dna));
+  let protein = protein.trim_end().to_ascii_uppercase();
+
+  let mut ids = Vec::new();
+  for code in GeneticCode::all() {
+    if try!(translate_rna_into_protein_with_code(&rna, &code)) == protein { ids.push(code.id()); }
+  }
+  Ok(ids)
+}
+
+/// This enumeration represents strand of double-stranded DNA
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Strand {
+  Forward,
+  Reverse,
+}
+
+/// This structure represents translation of a reading frame, `offset` is position
+/// of the first codon on the strand (0, 1 or 2)
+#[derive(Clone, PartialEq, Debug)]
+pub struct ReadingFrame {
+  pub strand: Strand,
+  pub offset: usize,
+  pub protein: String,
+}
+
+/// This function translates DNA or RNA string from the first nucleotide using genetic code
+/// `code`, trailing partial codon is ignored. Stop codons are translated into `*` and
+/// translation continues, codons with IUPAC ambiguity codes are translated into `X` unless
+/// all of their nucleotides encode the same amino acid. Nucleotides are case-insensitive.
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::prot::*;
+///
+/// let code = GeneticCode::standard();
+/// assert_eq!(translate_frame("ATGTAAGCNNNNTG", &code).unwrap(), "M*AX");
+/// assert_eq!(translate_frame("augugaUU", &code).unwrap(), "M*");
+/// assert_eq!(translate_frame("ATZ", &code).unwrap_err(), UnknownNucleotide('Z'));
+/// ```
+pub fn translate_frame(seq: &str, code: &GeneticCode) -> RosalindResult<String> {
+  let nucleotides: Vec<char> = seq.chars().filter(|&nucleotide| nucleotide != '\n').collect();
+  nucleotides
+    .chunks(3)
+    .filter(|codon| codon.len() == 3)
+    .map(|codon| code.translate_codon(codon))
+    .collect()
+}
+
+/// This function translates DNA or RNA string in all six reading frames: three frames
+/// of the forward strand followed by three frames of the reverse complement strand
+///
+/// ## Examples
+/// ```
+/// use rosalind::prot::*;
+///
+/// let frames = translate_six_frames("ATGGCCTAAGG", &GeneticCode::standard()).unwrap();
+/// let proteins: Vec<&str> = frames.iter().map(|frame| &*frame.protein).collect();
+/// assert_eq!(proteins, vec!["MA*", "WPK", "GLR", "P*A", "LRP", "LGH"]);
+/// assert_eq!((frames[4].strand, frames[4].offset), (Strand::Reverse, 1));
+/// ```
+pub fn translate_six_frames(seq: &str, code: &GeneticCode) -> RosalindResult<Vec<ReadingFrame>> {
+  let forward: String = seq.chars().filter(|&nucleotide| nucleotide != '\n').collect();
+  let reverse = try!(reverse_complement_dna_iupac(&forward.replace(|nucleotide: char| nucleotide == 'U' || nucleotide == 'u', "T")));
+
+  let mut frames = Vec::with_capacity(6);
+  for &(strand, dna) in [(Strand::Forward, &forward), (Strand::Reverse, &reverse)].iter() {
+    for offset in 0..3 {
+      let protein = try!(translate_frame(dna.get(offset..).unwrap_or(""), code));
+      frames.push(ReadingFrame {strand, offset, protein});
+    }
+  }
+  Ok(frames)
+}
+
 /// This function returns the number of different mRNA strings from which protein
 /// could have been translated
 ///
@@ -171,7 +472,10 @@ pub fn get_protein_mass(protein: &str) -> RosalindResult<f64> {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use super::super::RosalindError::{CodonParseError, UnknownCodon, UnknownAminoAcid};
+  use super::{GENETIC_CODES, amino_acid_into_codon};
+  use super::super::RosalindError::{CodonParseError, UnknownCodon, UnknownAminoAcid, UnknownGeneticCode};
+  use super::super::RosalindError::UnknownNucleotide;
+  use super::super::constants::CODON_STOP_SYMBOL;
 
   #[test]
   fn it_should_translate_rna_into_protein() {
@@ -195,6 +499,19 @@ mod tests {
     assert_eq!(translate_rna_into_protein("ZZZ").unwrap_err(), UnknownCodon("ZZZ".to_string()));
   }
 
+  #[test]
+  fn it_should_translate_lowercase_rna_into_protein() {
+    assert_eq!(translate_rna_into_protein("auggccAUGgcgcccagaacugagaucaauaguacccguauuaacggguga").unwrap(),
+      "MAMAPRTEINSTRING");
+  }
+
+  #[test]
+  fn it_should_handle_lowercase_amino_acids() {
+    assert_eq!(get_number_of_rna_from_protein("ma").unwrap(), 12);
+    assert_eq!(get_protein_mass("skadyek").unwrap(), 821.392f64);
+    assert_eq!(get_protein_mass("ab").unwrap_err(), UnknownAminoAcid('b'));
+  }
+
   #[test]
   fn it_should_return_number_of_rna_from_protein() {
     assert_eq!(get_number_of_rna_from_protein("MA").unwrap(), 12);
@@ -224,4 +541,125 @@ mod tests {
   fn it_should_not_calculate_protein_mass() {
     assert_eq!(get_protein_mass("AB").unwrap_err(), UnknownAminoAcid('B'));
   }
+
+  #[test]
+  fn it_should_define_every_codon_in_genetic_codes() {
+    for &(_, _, amino_acids, start_codons) in GENETIC_CODES.iter() {
+      assert_eq!(amino_acids.len(), 64);
+      assert_eq!(start_codons.len(), 64);
+    }
+  }
+
+  #[test]
+  fn it_should_match_standard_genetic_code_with_codon_list() {
+    let code = GeneticCode::standard();
+    for amino_acid in "ACDEFGHIKLMNPQRSTVWY\x00".chars() {
+      for codon in amino_acid_into_codon(amino_acid).unwrap() {
+        assert_eq!(code.amino_acid(codon).unwrap(), amino_acid);
+      }
+    }
+  }
+
+  #[test]
+  fn it_should_return_start_and_stop_codons() {
+    assert_eq!(GeneticCode::standard().start_codons(), vec!["UUG", "CUG", "AUG"]);
+    assert_eq!(GeneticCode::standard().stop_codons(), vec!["UAA", "UAG", "UGA"]);
+    assert_eq!(GeneticCode::new(2).unwrap().start_codons(), vec!["AUU", "AUC", "AUA", "AUG", "GUG"]);
+    assert_eq!(GeneticCode::new(2).unwrap().stop_codons(), vec!["UAA", "UAG", "AGA", "AGG"]);
+    assert_eq!(GeneticCode::new(11).unwrap().is_start_codon("gug").unwrap(), true);
+    assert_eq!(GeneticCode::new(6).unwrap().stop_codons(), vec!["UGA"]);
+  }
+
+  #[test]
+  fn it_should_translate_rna_with_alternative_genetic_codes() {
+    let rna = "AUGUGAAUAAGAUAA";
+    assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::standard()).unwrap(), "M");
+    assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::new(2).unwrap()).unwrap(), "MWM");
+    assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::new(5).unwrap()).unwrap(), "MWMS");
+    assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::new(9).unwrap()).unwrap(), "MWIS");
+    assert_eq!(translate_rna_into_protein_with_code("UAAUAG", &GeneticCode::new(6).unwrap()).unwrap(), "QQ");
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_genetic_code_requested() {
+    for &id in [0, 7, 8, 17, 20, 34].iter() {
+      assert_eq!(GeneticCode::new(id).unwrap_err(), UnknownGeneticCode(id));
+    }
+    assert_eq!(GeneticCode::all().len(), 27);
+    assert_eq!(GeneticCode::default(), GeneticCode::standard());
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_codon_translated() {
+    let code = GeneticCode::standard();
+    assert_eq!(code.amino_acid("AUT").unwrap_err(), UnknownCodon("AUT".to_string()));
+    assert_eq!(code.amino_acid("AU").unwrap_err(), UnknownCodon("AU".to_string()));
+    assert_eq!(code.is_stop_codon("uga").unwrap(), true);
+    assert_eq!(code.amino_acid("UAG").unwrap(), CODON_STOP_SYMBOL);
+  }
+
+  #[test]
+  fn it_should_find_genetic_codes_translating_dna() {
+    let dna = "ATGGCCATGGCGCCCAGAACTGAGATCAATAGTACCCGTATTAACGGGTGA";
+    assert!(find_genetic_codes(dna, "MAMAPRTEINSTRING").unwrap().contains(&1));
+    assert_eq!(find_genetic_codes("ATGTGA", "MW").unwrap(), vec![2, 3, 4, 5, 9, 13, 14, 21, 24, 27, 28, 31, 33]);
+    assert_eq!(find_genetic_codes("ATGTGA", "MK").unwrap(), vec![]);
+  }
+
+  #[test]
+  fn it_should_translate_frame_ignoring_partial_codon() {
+    let code = GeneticCode::standard();
+    assert_eq!(translate_frame("ATGGCCATGGCGCCCAGAACTGAGATCAATAGTACCCGTATTAACGGGTGAAC", &code).unwrap(),
+      "MAMAPRTEINSTRING*");
+    assert_eq!(translate_frame("AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA", &code).unwrap(),
+      "MAMAPRTEINSTRING*");
+    assert_eq!(translate_frame("AT", &code).unwrap(), "");
+    assert_eq!(translate_frame("ATG\nTAAG\n", &code).unwrap(), "M*");
+  }
+
+  #[test]
+  fn it_should_translate_ambiguous_codons() {
+    let code = GeneticCode::standard();
+    assert_eq!(translate_frame("GCNGGNCCNACNGTN", &code).unwrap(), "AGPTV");
+    assert_eq!(translate_frame("TAR", &code).unwrap(), "*");
+    assert_eq!(translate_frame("TRA", &code).unwrap(), "*");
+    assert_eq!(translate_frame("TRG", &code).unwrap(), "X");
+    assert_eq!(translate_frame("NNNATH", &code).unwrap(), "XI");
+    assert_eq!(translate_frame("ATH", &GeneticCode::new(2).unwrap()).unwrap(), "X");
+  }
+
+  #[test]
+  fn it_should_translate_frame_with_alternative_genetic_code() {
+    assert_eq!(translate_frame("ATGTGAAGA", &GeneticCode::new(2).unwrap()).unwrap(), "MW*");
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_translated() {
+    let code = GeneticCode::standard();
+    assert_eq!(translate_frame("ATGAZ", &code).unwrap(), "M");
+    assert_eq!(translate_frame("ATGAZG", &code).unwrap_err(), UnknownNucleotide('Z'));
+    assert_eq!(translate_six_frames("ATGAZ", &code).unwrap_err(), UnknownNucleotide('Z'));
+  }
+
+  #[test]
+  fn it_should_translate_six_frames() {
+    let frames = translate_six_frames("AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG", &GeneticCode::standard()).unwrap();
+    let expected = vec![
+      (Strand::Forward, 0, "SHVANSGYMGMTPRLGLESLLE*A*MIRVASQ"),
+      (Strand::Forward, 1, "AM*LTQVTWG*PRDLD*SLFWNKPE*SE*HL"),
+      (Strand::Forward, 2, "PCS*LRLHGDDPATWIRVSFGISLNDPSSIS"),
+      (Strand::Reverse, 0, "LRCYSDHSGLFQKRL*SKSRGHPHVT*VSYMA"),
+      (Strand::Reverse, 1, "*DATRIIQAYSKRDSNPSRGVIPM*PELATW"),
+      (Strand::Reverse, 2, "EMLLGSFRLIPKETLIQVAGSSPCNLS*LHG"),
+    ];
+    let frames: Vec<(Strand, usize, &str)> = frames.iter().map(|f| (f.strand, f.offset, &*f.protein)).collect();
+    assert_eq!(frames, expected);
+  }
+
+  #[test]
+  fn it_should_translate_six_frames_of_rna() {
+    let dna_frames = translate_six_frames("ATGGCCTAAGG", &GeneticCode::standard()).unwrap();
+    let rna_frames = translate_six_frames("auggccuaagg", &GeneticCode::standard()).unwrap();
+    assert_eq!(dna_frames, rna_frames);
+  }
 }
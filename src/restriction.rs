@@ -0,0 +1,157 @@
+//! Module for restriction enzymes and restriction digests
+
+use RosalindResult;
+use RosalindError::UnknownRestrictionEnzyme;
+use seq::DnaSeq;
+
+/// This structure describes restriction enzyme recognizing palindromic `site`,
+/// the enzyme cuts forward strand after `cut` nucleotides of the site
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RestrictionEnzyme {
+  pub name: &'static str,
+  pub site: &'static str,
+  pub cut: usize,
+}
+
+/// Common restriction enzymes with palindromic recognition sites
+pub const RESTRICTION_ENZYMES: [RestrictionEnzyme; 20] = [
+  RestrictionEnzyme {name: "AluI", site: "AGCT", cut: 2},
+  RestrictionEnzyme {name: "BamHI", site: "GGATCC", cut: 1},
+  RestrictionEnzyme {name: "BglII", site: "AGATCT", cut: 1},
+  RestrictionEnzyme {name: "ClaI", site: "ATCGAT", cut: 2},
+  RestrictionEnzyme {name: "EcoRI", site: "GAATTC", cut: 1},
+  RestrictionEnzyme {name: "EcoRV", site: "GATATC", cut: 3},
+  RestrictionEnzyme {name: "HaeIII", site: "GGCC", cut: 2},
+  RestrictionEnzyme {name: "HindIII", site: "AAGCTT", cut: 1},
+  RestrictionEnzyme {name: "HpaII", site: "CCGG", cut: 1},
+  RestrictionEnzyme {name: "KpnI", site: "GGTACC", cut: 5},
+  RestrictionEnzyme {name: "MboI", site: "GATC", cut: 0},
+  RestrictionEnzyme {name: "NcoI", site: "CCATGG", cut: 1},
+  RestrictionEnzyme {name: "NdeI", site: "CATATG", cut: 2},
+  RestrictionEnzyme {name: "NotI", site: "GCGGCCGC", cut: 2},
+  RestrictionEnzyme {name: "PstI", site: "CTGCAG", cut: 5},
+  RestrictionEnzyme {name: "SacI", site: "GAGCTC", cut: 5},
+  RestrictionEnzyme {name: "SalI", site: "GTCGAC", cut: 1},
+  RestrictionEnzyme {name: "SmaI", site: "CCCGGG", cut: 3},
+  RestrictionEnzyme {name: "TaqI", site: "TCGA", cut: 1},
+  RestrictionEnzyme {name: "XhoI", site: "CTCGAG", cut: 1},
+];
+
+/// This function returns restriction enzyme by its name, names are case-insensitive
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::UnknownRestrictionEnzyme;
+/// use rosalind::restriction::*;
+///
+/// assert_eq!(restriction_enzyme("EcoRI").unwrap().site, "GAATTC");
+/// assert_eq!(restriction_enzyme("ecori").unwrap().cut, 1);
+/// assert_eq!(restriction_enzyme("EcoXI").unwrap_err(), UnknownRestrictionEnzyme("EcoXI".to_string()));
+/// ```
+pub fn restriction_enzyme(name: &str) -> RosalindResult<RestrictionEnzyme> {
+  RESTRICTION_ENZYMES
+    .iter()
+    .find(|enzyme| enzyme.name.eq_ignore_ascii_case(name))
+    .cloned()
+    .ok_or_else(|| UnknownRestrictionEnzyme(name.to_string()))
+}
+
+/// This function returns zero-based positions in DNA string where restriction enzyme
+/// cuts the forward strand, i.e. the first nucleotide after each cut. Overlapping
+/// occurrences of the recognition site are all cut.
+///
+/// ## Examples
+/// ```
+/// use rosalind::restriction::*;
+///
+/// let ecori = restriction_enzyme("EcoRI").unwrap();
+/// assert_eq!(cut_sites("AAGAATTCAAAGAATTCA", &ecori).unwrap(), vec![3, 12]);
+/// ```
+pub fn cut_sites(dna: &str, enzyme: &RestrictionEnzyme) -> RosalindResult<Vec<usize>> {
+  let dna = try!(DnaSeq::new(dna)).to_string();
+  if dna.len() < enzyme.site.len() { return Ok(vec![]); }
+  Ok((0..dna.len() - enzyme.site.len() + 1)
+    .filter(|&i| dna[i..].starts_with(enzyme.site))
+    .map(|i| i + enzyme.cut)
+    .collect())
+}
+
+/// This function digests linear DNA string with restriction enzymes and returns
+/// lengths of fragments in order of their positions
+///
+/// ## Examples
+/// ```
+/// use rosalind::restriction::*;
+///
+/// let enzymes = [restriction_enzyme("EcoRI").unwrap(), restriction_enzyme("BamHI").unwrap()];
+/// assert_eq!(digest("AAGAATTCAAAGGATCCAAA", &enzymes).unwrap(), vec![3, 9, 8]);
+/// assert_eq!(digest("AAAA", &enzymes).unwrap(), vec![4]);
+/// ```
+pub fn digest(dna: &str, enzymes: &[RestrictionEnzyme]) -> RosalindResult<Vec<usize>> {
+  let len = try!(DnaSeq::new(dna)).len();
+
+  let mut cuts = Vec::new();
+  for enzyme in enzymes {
+    cuts.extend(try!(cut_sites(dna, enzyme)));
+  }
+  cuts.push(len);
+  cuts.sort();
+  cuts.dedup();
+
+  let mut previous_cut = 0;
+  let mut fragments = Vec::with_capacity(cuts.len());
+  for cut in cuts {
+    if cut > previous_cut { fragments.push(cut - previous_cut); }
+    previous_cut = cut;
+  }
+  Ok(fragments)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use revc::reverse_complement_dna;
+  use super::super::RosalindError::{UnknownNucleotide, UnknownRestrictionEnzyme};
+
+  #[test]
+  fn it_should_have_palindromic_recognition_sites() {
+    for enzyme in RESTRICTION_ENZYMES.iter() {
+      assert_eq!(reverse_complement_dna(enzyme.site).unwrap(), enzyme.site);
+      assert!(enzyme.cut <= enzyme.site.len());
+    }
+  }
+
+  #[test]
+  fn it_should_return_restriction_enzyme_by_name() {
+    assert_eq!(restriction_enzyme("hindiii").unwrap(), RestrictionEnzyme {name: "HindIII", site: "AAGCTT", cut: 1});
+    assert_eq!(restriction_enzyme("").unwrap_err(), UnknownRestrictionEnzyme("".to_string()));
+  }
+
+  #[test]
+  fn it_should_return_cut_sites() {
+    let dna = "GCGGCCGCAAGCGGCCGC";
+    assert_eq!(cut_sites(dna, &restriction_enzyme("NotI").unwrap()).unwrap(), vec![2, 12]);
+    assert_eq!(cut_sites(dna, &restriction_enzyme("HaeIII").unwrap()).unwrap(), vec![4, 14]);
+    assert_eq!(cut_sites("gatcgatc", &restriction_enzyme("MboI").unwrap()).unwrap(), vec![0, 4]);
+    assert_eq!(cut_sites("GAT", &restriction_enzyme("MboI").unwrap()).unwrap(), vec![]);
+    assert_eq!(cut_sites("GATZ", &restriction_enzyme("MboI").unwrap()).unwrap_err(), UnknownNucleotide('Z'));
+  }
+
+  #[test]
+  fn it_should_digest_dna() {
+    let enzymes = [restriction_enzyme("NotI").unwrap(), restriction_enzyme("HaeIII").unwrap()];
+    assert_eq!(digest("GCGGCCGCAAGCGGCCGC", &enzymes).unwrap(), vec![2, 2, 8, 2, 4]);
+    assert_eq!(digest("GATCGATC", &[restriction_enzyme("MboI").unwrap()]).unwrap(), vec![4, 4]);
+    assert_eq!(digest("", &enzymes).unwrap(), vec![]);
+  }
+
+  #[test]
+  fn it_should_cut_overlapping_sites() {
+    let clai = restriction_enzyme("ClaI").unwrap();
+    let noti = restriction_enzyme("NotI").unwrap();
+    assert_eq!(cut_sites("ATCGATCGAT", &clai).unwrap(), vec![2, 6]);
+    assert_eq!(cut_sites("GCGGCCGCGGCCGC", &noti).unwrap(), vec![2, 8]);
+    assert_eq!(digest("GCGGCCGCGGCCGC", &[noti]).unwrap(), vec![2, 6, 6]);
+    assert_eq!(digest("ATCGATCGAT", &[clai]).unwrap(), vec![2, 4, 4]);
+  }
+}
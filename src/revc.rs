@@ -1,9 +1,14 @@
 //! Module for `Complementing a Strand of DNA`
 
+use std::fmt;
 use RosalindResult;
-use RosalindError::UnknownNucleotide;
+use RosalindError::InvalidInputParameters;
+use seq::{DnaBase, DnaSeq};
+use iupac;
+use packed::PackedDna;
 
-/// This function returns a reverse complement of a DNA string
+/// This function returns a reverse complement of a DNA string, lowercase
+/// nucleotides are complemented into uppercase
 ///
 /// # Examples
 /// ```
@@ -13,28 +18,145 @@ use RosalindError::UnknownNucleotide;
 /// let dna = "AAAACCCGGT";
 /// assert_eq!(reverse_complement_dna(dna).unwrap(), "ACCGGGTTTT");
 /// assert_eq!(reverse_complement_dna("\n").unwrap(), "");
+/// assert_eq!(reverse_complement_dna("aaaCC").unwrap(), "GGTTT");
 /// assert_eq!(reverse_complement_dna("Z").unwrap_err(), UnknownNucleotide('Z'));
 /// ```
 pub fn reverse_complement_dna(dna: &str) -> RosalindResult<String> {
-  let mut complement_dna = String::new();
-  for nucleotide in dna.chars().rev() {
-    match nucleotide {
-      'A' => complement_dna.push('T'),
-      'C' => complement_dna.push('G'),
-      'G' => complement_dna.push('C'),
-      'T' => complement_dna.push('A'),
-      '\n' => continue,
-      _ => return Err(UnknownNucleotide(nucleotide))
+  Ok(try!(DnaSeq::new(dna)).reverse_complement().to_string())
+}
+
+/// This function returns a reverse complement of a DNA string with IUPAC
+/// ambiguity codes, e.g. `R` and `Y`, `K` and `M`, `B` and `V`, `D` and `H`
+/// complement each other
+///
+/// # Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::revc::*;
+///
+/// assert_eq!(reverse_complement_dna_iupac("AAANNRKBD").unwrap(), "HVMYNNTTT");
+/// assert_eq!(reverse_complement_dna_iupac("Z").unwrap_err(), UnknownNucleotide('Z'));
+/// ```
+pub fn reverse_complement_dna_iupac(dna: &str) -> RosalindResult<String> {
+  dna.chars()
+    .rev()
+    .filter(|&nucleotide| nucleotide != '\n')
+    .map(|nucleotide| iupac::complement(nucleotide).map(|c| c.to_ascii_uppercase()))
+    .collect()
+}
+
+/// This function returns a reverse complement of a soft-masked DNA string
+/// preserving case of nucleotides, so masked (lowercase) regions stay masked
+///
+/// # Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::revc::*;
+///
+/// assert_eq!(reverse_complement_dna_soft_masked("AAaacCCttGT").unwrap(), "ACaaGGgttTT");
+/// assert_eq!(reverse_complement_dna_soft_masked("z").unwrap_err(), UnknownNucleotide('z'));
+/// assert_eq!(reverse_complement_dna_soft_masked("ACnn").unwrap_err(), UnknownNucleotide('n'));
+/// ```
+pub fn reverse_complement_dna_soft_masked(dna: &str) -> RosalindResult<String> {
+  dna.chars()
+    .rev()
+    .filter(|&nucleotide| nucleotide != '\n')
+    .map(|nucleotide| {
+      let base = try!(DnaBase::from_char(nucleotide)).complement().to_char();
+      Ok(if nucleotide.is_ascii_lowercase() { base.to_ascii_lowercase() } else { base })
+    })
+    .collect()
+}
+
+/// This function returns a reverse complement of a packed DNA string,
+/// nucleotides are complemented and reversed word by word without unpacking
+///
+/// # Examples
+/// ```
+/// use rosalind::packed::PackedDna;
+/// use rosalind::revc::*;
+///
+/// let dna = PackedDna::new("AAAACCCGGTN").unwrap();
+/// assert_eq!(reverse_complement_packed_dna(&dna).to_string(), "NACCGGGTTTT");
+/// ```
+pub fn reverse_complement_packed_dna(dna: &PackedDna) -> PackedDna {
+  dna.reverse_complement()
+}
+
+/// This structure contains position (starting from 1) and length of a reverse palindrome
+#[derive(PartialEq, Debug)]
+pub struct ReversePalindrome {
+  pub position: usize,
+  pub length: usize,
+}
+
+impl fmt::Display for ReversePalindrome {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} {}", self.position, self.length)
+  }
+}
+
+/// This function finds reverse palindromes of length from 4 to 12 in DNA string, i.e.
+/// substrings which are equal to their reverse complement (locates restriction sites)
+///
+/// # Examples
+/// ```
+/// use rosalind::revc::*;
+///
+/// let palindromes = reverse_palindromes("TCAATGCATGCGGGTCTATATGCAT").unwrap();
+/// let palindromes: Vec<String> = palindromes.iter().map(|p| p.to_string()).collect();
+/// assert_eq!(palindromes, vec!["4 6", "5 4", "6 6", "7 4", "17 4", "18 4", "20 6", "21 4"]);
+/// ```
+pub fn reverse_palindromes(dna: &str) -> RosalindResult<Vec<ReversePalindrome>> {
+  reverse_palindromes_with_length(dna, 4, 12)
+}
+
+/// This function finds reverse palindromes of length from `min_length` to `max_length`
+/// in DNA string, palindromes are ordered by position and length
+///
+/// # Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::revc::*;
+///
+/// let palindromes = reverse_palindromes_with_length("GAATTC", 2, 6).unwrap();
+/// assert_eq!(palindromes, vec![
+///   ReversePalindrome {position: 1, length: 6},
+///   ReversePalindrome {position: 2, length: 4},
+///   ReversePalindrome {position: 3, length: 2},
+/// ]);
+/// assert_eq!(reverse_palindromes_with_length("GAATTC", 6, 4).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn reverse_palindromes_with_length(dna: &str, min_length: usize, max_length: usize)
+  -> RosalindResult<Vec<ReversePalindrome>> {
+  if min_length > max_length { return Err(InvalidInputParameters); }
+
+  let dna = try!(DnaSeq::new(dna));
+  let bases = dna.bases();
+
+  // odd palindromes are impossible as the middle nucleotide differs from its complement,
+  // so every palindrome is found by extending around the center between two nucleotides
+  let mut palindromes = Vec::new();
+  for center in 1..bases.len() {
+    let mut half = 1;
+    while half <= center && center + half <= bases.len() && 2 * half <= max_length
+      && bases[center - half] == bases[center + half - 1].complement() {
+      if 2 * half >= min_length {
+        palindromes.push(ReversePalindrome {position: center - half + 1, length: 2 * half});
+      }
+      half += 1;
     }
   }
 
-  Ok(complement_dna)
+  palindromes.sort_by_key(|palindrome| (palindrome.position, palindrome.length));
+  Ok(palindromes)
 }
 
 #[cfg(test)]
 mod tests {
-  use super::reverse_complement_dna;
-  use super::super::RosalindError::UnknownNucleotide;
+  use super::{reverse_complement_dna, reverse_complement_dna_iupac, reverse_complement_dna_soft_masked};
+  use super::{reverse_palindromes, reverse_palindromes_with_length};
+  use super::super::RosalindError::{UnknownNucleotide, InvalidInputParameters};
 
   #[test]
   fn it_should_return_error_when_unknown_nucleotide_found() {
@@ -51,4 +173,47 @@ mod tests {
   fn it_should_skip_new_line_symbol() {
     assert_eq!(reverse_complement_dna("\n").unwrap(), "");
   }
+
+  #[test]
+  fn it_should_reverse_complement_dna_with_ambiguity_codes() {
+    assert_eq!(reverse_complement_dna_iupac("ACGTRYSWKMBDHVN\n").unwrap(), "NBDHVKMWSRYACGT");
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_ambiguity_code_found() {
+    assert_eq!(reverse_complement_dna_iupac("ACGU").unwrap_err(), UnknownNucleotide('U'));
+  }
+
+  #[test]
+  fn it_should_reverse_complement_lowercase_dna() {
+    assert_eq!(reverse_complement_dna("aaaaCCCggt").unwrap(), "ACCGGGTTTT");
+    assert_eq!(reverse_complement_dna_iupac("aaNNry").unwrap(), "RYNNTT");
+  }
+
+  #[test]
+  fn it_should_preserve_soft_masking() {
+    assert_eq!(reverse_complement_dna_soft_masked("AAAAcccGGT\n").unwrap(), "ACCgggTTTT");
+    assert_eq!(reverse_complement_dna_soft_masked("AAnnGT").unwrap_err(), UnknownNucleotide('n'));
+  }
+
+  #[test]
+  fn it_should_find_reverse_palindromes() {
+    let palindromes = reverse_palindromes("TCAATGCATGCGGGTCTATATGCAT").unwrap();
+    let palindromes: Vec<(usize, usize)> = palindromes.iter().map(|p| (p.position, p.length)).collect();
+    assert_eq!(palindromes, vec![(4, 6), (5, 4), (6, 6), (7, 4), (17, 4), (18, 4), (20, 6), (21, 4)]);
+  }
+
+  #[test]
+  fn it_should_find_reverse_palindromes_with_length() {
+    let palindromes = reverse_palindromes_with_length("aAGCTTt", 6, 6).unwrap();
+    let palindromes: Vec<(usize, usize)> = palindromes.iter().map(|p| (p.position, p.length)).collect();
+    assert_eq!(palindromes, vec![(1, 6)]);
+    assert_eq!(reverse_palindromes("").unwrap(), vec![]);
+    assert_eq!(reverse_palindromes_with_length("ATAT", 3, 2).unwrap_err(), InvalidInputParameters);
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found_in_palindromes() {
+    assert_eq!(reverse_palindromes("GAATTCZ").unwrap_err(), UnknownNucleotide('Z'));
+  }
 }
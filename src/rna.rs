@@ -2,9 +2,11 @@
 
 use RosalindResult;
 use RosalindError::UnknownNucleotide;
+use seq::{DnaBase, DnaSeq};
+use iupac;
 
 /// This function transcribes DNA into RNA via replacung T nucleotide in DNA
-/// into U nucleotide in RNA
+/// into U nucleotide in RNA, lowercase nucleotides are transcribed into uppercase
 ///
 /// # Examples
 /// ```
@@ -14,25 +16,66 @@ use RosalindError::UnknownNucleotide;
 /// let dna = "GATGGAACTTGACTACGTAAATT";
 /// assert_eq!(transcribe_dna_into_rna(dna).unwrap(), "GAUGGAACUUGACUACGUAAAUU");
 /// assert_eq!(transcribe_dna_into_rna("\n").unwrap(), "");
+/// assert_eq!(transcribe_dna_into_rna("gatTA").unwrap(), "GAUUA");
 /// assert_eq!(transcribe_dna_into_rna("Z").unwrap_err(), UnknownNucleotide('Z'));
 /// ```
 pub fn transcribe_dna_into_rna(dna: &str) -> RosalindResult<String> {
-  let mut rna = String::new();
+  Ok(try!(DnaSeq::new(dna)).transcribe().to_string())
+}
+
+/// This function transcribes DNA with IUPAC ambiguity codes into RNA,
+/// ambiguity codes are kept as is
+///
+/// # Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::rna::*;
+///
+/// assert_eq!(transcribe_dna_into_rna_iupac("GATNNRYT").unwrap(), "GAUNNRYU");
+/// assert_eq!(transcribe_dna_into_rna_iupac("Z").unwrap_err(), UnknownNucleotide('Z'));
+/// ```
+pub fn transcribe_dna_into_rna_iupac(dna: &str) -> RosalindResult<String> {
+  let mut rna = String::with_capacity(dna.len());
   for nucleotide in dna.chars() {
-    match nucleotide {
-        'A' | 'C' | 'G' => rna.push(nucleotide),
-        'T' => rna.push('U'),
-        '\n' => continue,
-        _ => return Err(UnknownNucleotide(nucleotide)),
+    match nucleotide.to_ascii_uppercase() {
+      'T' => rna.push('U'),
+      'U' => return Err(UnknownNucleotide(nucleotide)),
+      '\n' => continue,
+      code => {
+        try!(iupac::nucleotide_mask(code));
+        rna.push(code);
+      }
     }
   }
 
   Ok(rna)
 }
 
+/// This function transcribes soft-masked DNA into RNA preserving case of
+/// nucleotides, so masked (lowercase) regions stay masked
+///
+/// # Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::rna::*;
+///
+/// assert_eq!(transcribe_dna_into_rna_soft_masked("GATtacaT").unwrap(), "GAUuacaU");
+/// assert_eq!(transcribe_dna_into_rna_soft_masked("u").unwrap_err(), UnknownNucleotide('u'));
+/// assert_eq!(transcribe_dna_into_rna_soft_masked("GAn").unwrap_err(), UnknownNucleotide('n'));
+/// ```
+pub fn transcribe_dna_into_rna_soft_masked(dna: &str) -> RosalindResult<String> {
+  dna.chars()
+    .filter(|&nucleotide| nucleotide != '\n')
+    .map(|nucleotide| {
+      let base = try!(DnaBase::from_char(nucleotide)).transcribe().to_char();
+      Ok(if nucleotide.is_ascii_lowercase() { base.to_ascii_lowercase() } else { base })
+    })
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
-  use super::transcribe_dna_into_rna;
+  use super::{transcribe_dna_into_rna, transcribe_dna_into_rna_iupac, transcribe_dna_into_rna_soft_masked};
   use super::super::RosalindError::UnknownNucleotide;
 
   #[test]
@@ -50,4 +93,27 @@ mod tests {
   fn it_should_skip_new_line_symbol() {
     assert_eq!(transcribe_dna_into_rna("\n").unwrap(), "");
   }
+
+  #[test]
+  fn it_should_transcribe_dna_with_ambiguity_codes_into_rna() {
+    assert_eq!(transcribe_dna_into_rna_iupac("NNGATRYSWKMBDHVT\n").unwrap(), "NNGAURYSWKMBDHVU");
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_ambiguity_code_found() {
+    assert_eq!(transcribe_dna_into_rna_iupac("GAU").unwrap_err(), UnknownNucleotide('U'));
+    assert_eq!(transcribe_dna_into_rna_iupac("GAZ").unwrap_err(), UnknownNucleotide('Z'));
+  }
+
+  #[test]
+  fn it_should_transcribe_lowercase_dna_into_rna() {
+    assert_eq!(transcribe_dna_into_rna("gatGGaact").unwrap(), "GAUGGAACU");
+    assert_eq!(transcribe_dna_into_rna_iupac("gatnn").unwrap(), "GAUNN");
+  }
+
+  #[test]
+  fn it_should_preserve_soft_masking() {
+    assert_eq!(transcribe_dna_into_rna_soft_masked("GATggaacttGAC\n").unwrap(), "GAUggaacuuGAC");
+    assert_eq!(transcribe_dna_into_rna_soft_masked("GATnnT").unwrap_err(), UnknownNucleotide('n'));
+  }
 }
@@ -0,0 +1,380 @@
+//! Module for typed nucleotide alphabets and validated DNA and RNA strings
+
+use std::fmt;
+use std::str::FromStr;
+use RosalindResult;
+use RosalindError;
+use RosalindError::UnknownNucleotide;
+use dna::DNANucleotides;
+
+/// This enumeration contains nucleotides of DNA
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum DnaBase {
+  A,
+  C,
+  G,
+  T,
+}
+
+impl DnaBase {
+  /// This function converts symbol into DNA nucleotide, symbols are case-insensitive
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::RosalindError::UnknownNucleotide;
+  /// use rosalind::seq::*;
+  ///
+  /// assert_eq!(DnaBase::from_char('G').unwrap(), DnaBase::G);
+  /// assert_eq!(DnaBase::from_char('g').unwrap(), DnaBase::G);
+  /// assert_eq!(DnaBase::from_char('U').unwrap_err(), UnknownNucleotide('U'));
+  /// ```
+  pub fn from_char(nucleotide: char) -> RosalindResult<DnaBase> {
+    match nucleotide.to_ascii_uppercase() {
+      'A' => Ok(DnaBase::A),
+      'C' => Ok(DnaBase::C),
+      'G' => Ok(DnaBase::G),
+      'T' => Ok(DnaBase::T),
+      _ => Err(UnknownNucleotide(nucleotide)),
+    }
+  }
+
+  /// This function converts DNA nucleotide into symbol
+  pub fn to_char(&self) -> char {
+    match *self {
+      DnaBase::A => 'A',
+      DnaBase::C => 'C',
+      DnaBase::G => 'G',
+      DnaBase::T => 'T',
+    }
+  }
+
+  /// This function returns complementary DNA nucleotide
+  pub fn complement(&self) -> DnaBase {
+    match *self {
+      DnaBase::A => DnaBase::T,
+      DnaBase::C => DnaBase::G,
+      DnaBase::G => DnaBase::C,
+      DnaBase::T => DnaBase::A,
+    }
+  }
+
+  /// This function returns RNA nucleotide transcribed from DNA nucleotide
+  pub fn transcribe(&self) -> RnaBase {
+    match *self {
+      DnaBase::A => RnaBase::A,
+      DnaBase::C => RnaBase::C,
+      DnaBase::G => RnaBase::G,
+      DnaBase::T => RnaBase::U,
+    }
+  }
+
+  /// This function checks whether nucleotide is guanine or cytosine
+  pub fn is_gc(&self) -> bool {
+    *self == DnaBase::G || *self == DnaBase::C
+  }
+}
+
+/// This enumeration contains nucleotides of RNA
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum RnaBase {
+  A,
+  C,
+  G,
+  U,
+}
+
+impl RnaBase {
+  /// This function converts symbol into RNA nucleotide, symbols are case-insensitive
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::RosalindError::UnknownNucleotide;
+  /// use rosalind::seq::*;
+  ///
+  /// assert_eq!(RnaBase::from_char('U').unwrap(), RnaBase::U);
+  /// assert_eq!(RnaBase::from_char('T').unwrap_err(), UnknownNucleotide('T'));
+  /// ```
+  pub fn from_char(nucleotide: char) -> RosalindResult<RnaBase> {
+    match nucleotide.to_ascii_uppercase() {
+      'A' => Ok(RnaBase::A),
+      'C' => Ok(RnaBase::C),
+      'G' => Ok(RnaBase::G),
+      'U' => Ok(RnaBase::U),
+      _ => Err(UnknownNucleotide(nucleotide)),
+    }
+  }
+
+  /// This function converts RNA nucleotide into symbol
+  pub fn to_char(&self) -> char {
+    match *self {
+      RnaBase::A => 'A',
+      RnaBase::C => 'C',
+      RnaBase::G => 'G',
+      RnaBase::U => 'U',
+    }
+  }
+
+  /// This function returns complementary RNA nucleotide
+  pub fn complement(&self) -> RnaBase {
+    match *self {
+      RnaBase::A => RnaBase::U,
+      RnaBase::C => RnaBase::G,
+      RnaBase::G => RnaBase::C,
+      RnaBase::U => RnaBase::A,
+    }
+  }
+
+  /// This function checks whether nucleotide is guanine or cytosine
+  pub fn is_gc(&self) -> bool {
+    *self == RnaBase::G || *self == RnaBase::C
+  }
+}
+
+fn gc_content<T, F>(bases: &[T], is_gc: F) -> f32 where F: Fn(&T) -> bool {
+  if bases.is_empty() { return 0f32; }
+  let gc_counter = bases.iter().filter(|base| is_gc(base)).count();
+  gc_counter as f32 * 100f32 / bases.len() as f32
+}
+
+/// This structure contains DNA string which has been validated on construction
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::dna::DNANucleotides;
+/// use rosalind::seq::*;
+///
+/// let dna = DnaSeq::new("AAAACCCGGT").unwrap();
+/// assert_eq!(dna.count(), DNANucleotides {A: 4, C: 3, G: 2, T: 1});
+/// assert_eq!(dna.reverse_complement().to_string(), "ACCGGGTTTT");
+/// assert_eq!(dna.transcribe().to_string(), "AAAACCCGGU");
+/// assert_eq!(dna.gc_content(), 50f32);
+/// assert_eq!(DnaSeq::new("AZ").unwrap_err(), UnknownNucleotide('Z'));
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct DnaSeq {
+  bases: Vec<DnaBase>,
+}
+
+impl DnaSeq {
+  /// This function validates DNA string, `\n` symbols are skipped
+  pub fn new(dna: &str) -> RosalindResult<DnaSeq> {
+    let bases: RosalindResult<Vec<DnaBase>> = dna
+      .chars()
+      .filter(|&nucleotide| nucleotide != '\n')
+      .map(DnaBase::from_char)
+      .collect();
+
+    Ok(DnaSeq::from_bases(try!(bases)))
+  }
+
+  /// This function creates DNA string from nucleotides
+  pub fn from_bases(bases: Vec<DnaBase>) -> DnaSeq {
+    DnaSeq { bases }
+  }
+
+  /// This function returns nucleotides of DNA string
+  pub fn bases(&self) -> &[DnaBase] {
+    &self.bases
+  }
+
+  /// This function returns length of DNA string
+  pub fn len(&self) -> usize {
+    self.bases.len()
+  }
+
+  /// This function checks whether DNA string is empty
+  pub fn is_empty(&self) -> bool {
+    self.bases.is_empty()
+  }
+
+  /// This function calculates amount of each nucleotide in DNA string
+  pub fn count(&self) -> DNANucleotides {
+    let mut dna_nucleotides = DNANucleotides {A: 0, C: 0, G: 0, T: 0};
+    for nucleotide in self.bases.iter() {
+      match *nucleotide {
+        DnaBase::A => dna_nucleotides.A += 1,
+        DnaBase::C => dna_nucleotides.C += 1,
+        DnaBase::G => dna_nucleotides.G += 1,
+        DnaBase::T => dna_nucleotides.T += 1,
+      }
+    }
+    dna_nucleotides
+  }
+
+  /// This function transcribes DNA string into RNA string
+  pub fn transcribe(&self) -> RnaSeq {
+    RnaSeq::from_bases(self.bases.iter().map(DnaBase::transcribe).collect())
+  }
+
+  /// This function returns reverse complement of DNA string
+  pub fn reverse_complement(&self) -> DnaSeq {
+    DnaSeq::from_bases(self.bases.iter().rev().map(DnaBase::complement).collect())
+  }
+
+  /// This function calculates GC content of DNA string in percents
+  pub fn gc_content(&self) -> f32 {
+    gc_content(&self.bases, DnaBase::is_gc)
+  }
+}
+
+impl FromStr for DnaSeq {
+  type Err = RosalindError;
+
+  fn from_str(dna: &str) -> RosalindResult<DnaSeq> {
+    DnaSeq::new(dna)
+  }
+}
+
+impl fmt::Display for DnaSeq {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let dna: String = self.bases.iter().map(DnaBase::to_char).collect();
+    write!(f, "{}", dna)
+  }
+}
+
+/// This structure contains RNA string which has been validated on construction
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::UnknownNucleotide;
+/// use rosalind::seq::*;
+///
+/// let rna: RnaSeq = "GAUGGAACUU".parse().unwrap();
+/// assert_eq!(rna.len(), 10);
+/// assert_eq!(rna.gc_content(), 40f32);
+/// assert_eq!(RnaSeq::new("GAT").unwrap_err(), UnknownNucleotide('T'));
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct RnaSeq {
+  bases: Vec<RnaBase>,
+}
+
+impl RnaSeq {
+  /// This function validates RNA string, `\n` symbols are skipped
+  pub fn new(rna: &str) -> RosalindResult<RnaSeq> {
+    let bases: RosalindResult<Vec<RnaBase>> = rna
+      .chars()
+      .filter(|&nucleotide| nucleotide != '\n')
+      .map(RnaBase::from_char)
+      .collect();
+
+    Ok(RnaSeq::from_bases(try!(bases)))
+  }
+
+  /// This function creates RNA string from nucleotides
+  pub fn from_bases(bases: Vec<RnaBase>) -> RnaSeq {
+    RnaSeq { bases }
+  }
+
+  /// This function returns nucleotides of RNA string
+  pub fn bases(&self) -> &[RnaBase] {
+    &self.bases
+  }
+
+  /// This function returns length of RNA string
+  pub fn len(&self) -> usize {
+    self.bases.len()
+  }
+
+  /// This function checks whether RNA string is empty
+  pub fn is_empty(&self) -> bool {
+    self.bases.is_empty()
+  }
+
+  /// This function calculates GC content of RNA string in percents
+  pub fn gc_content(&self) -> f32 {
+    gc_content(&self.bases, RnaBase::is_gc)
+  }
+}
+
+impl FromStr for RnaSeq {
+  type Err = RosalindError;
+
+  fn from_str(rna: &str) -> RosalindResult<RnaSeq> {
+    RnaSeq::new(rna)
+  }
+}
+
+impl fmt::Display for RnaSeq {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let rna: String = self.bases.iter().map(RnaBase::to_char).collect();
+    write!(f, "{}", rna)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use dna::DNANucleotides;
+  use super::super::RosalindError::UnknownNucleotide;
+
+  #[test]
+  fn it_should_convert_dna_bases() {
+    for nucleotide in "ACGT".chars() {
+      assert_eq!(DnaBase::from_char(nucleotide).unwrap().to_char(), nucleotide);
+    }
+    assert_eq!(DnaBase::from_char('U').unwrap_err(), UnknownNucleotide('U'));
+  }
+
+  #[test]
+  fn it_should_convert_rna_bases() {
+    for nucleotide in "ACGU".chars() {
+      assert_eq!(RnaBase::from_char(nucleotide).unwrap().to_char(), nucleotide);
+    }
+    assert_eq!(RnaBase::from_char('T').unwrap_err(), UnknownNucleotide('T'));
+  }
+
+  #[test]
+  fn it_should_convert_lowercase_bases() {
+    assert_eq!(DnaBase::from_char('t').unwrap(), DnaBase::T);
+    assert_eq!(RnaBase::from_char('u').unwrap(), RnaBase::U);
+    assert_eq!(DnaBase::from_char('u').unwrap_err(), UnknownNucleotide('u'));
+    assert_eq!(DnaSeq::new("acGT").unwrap().to_string(), "ACGT");
+  }
+
+  #[test]
+  fn it_should_complement_bases() {
+    assert_eq!(DnaBase::A.complement(), DnaBase::T);
+    assert_eq!(DnaBase::G.complement(), DnaBase::C);
+    assert_eq!(RnaBase::A.complement(), RnaBase::U);
+    assert_eq!(RnaBase::C.complement(), RnaBase::G);
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(DnaSeq::new("ACGZ").unwrap_err(), UnknownNucleotide('Z'));
+    assert_eq!(RnaSeq::new("ACGT").unwrap_err(), UnknownNucleotide('T'));
+  }
+
+  #[test]
+  fn it_should_skip_new_line_symbol() {
+    assert_eq!(DnaSeq::new("AC\nGT\n").unwrap().to_string(), "ACGT");
+    assert_eq!(RnaSeq::new("AC\nGU\n").unwrap().to_string(), "ACGU");
+  }
+
+  #[test]
+  fn it_should_count_dna_nucleotides() {
+    let dna = DnaSeq::new("AGCTTTTCATTCTGACTGCAACGGGCAATATGTCTCTGTGTGGATTAAAAAAAGAGTGTCTGATAGCAGC").unwrap();
+    assert_eq!(dna.count(), DNANucleotides {A: 20, C: 12, G: 17, T: 21});
+  }
+
+  #[test]
+  fn it_should_transcribe_dna_into_rna() {
+    let dna = DnaSeq::new("GATGGAACTTGACTACGTAAATT").unwrap();
+    assert_eq!(dna.transcribe(), RnaSeq::new("GAUGGAACUUGACUACGUAAAUU").unwrap());
+  }
+
+  #[test]
+  fn it_should_reverse_complement_dna() {
+    let dna: DnaSeq = "AAAACCCGGT".parse().unwrap();
+    assert_eq!(dna.reverse_complement().to_string(), "ACCGGGTTTT");
+  }
+
+  #[test]
+  fn it_should_calculate_gc_content() {
+    assert_eq!(DnaSeq::new("").unwrap().gc_content(), 0f32);
+    assert_eq!(DnaSeq::new("AGCTATAG").unwrap().gc_content(), 37.5f32);
+    assert_eq!(RnaSeq::new("AGCUAUAG").unwrap().gc_content(), 37.5f32);
+  }
+}
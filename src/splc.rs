@@ -0,0 +1,177 @@
+//! Module for `RNA Splicing`
+
+use std::ops::Range;
+use RosalindResult;
+use RosalindError::InvalidInputParameters;
+use fasta::{FastaReader, FastaRecord};
+use rna::transcribe_dna_into_rna;
+use prot::translate_rna_into_protein;
+
+/// This function removes all occurrences of introns from DNA string, introns
+/// are removed in the given order
+///
+/// ## Examples
+/// ```
+/// use rosalind::splc::*;
+///
+/// assert_eq!(remove_introns("ATGCCCTTTGGGTAA", &["CCC", "GGG"]), "ATGTTTTAA");
+/// assert_eq!(remove_introns("ATGAAAATAA", &["AA"]), "ATGT");
+/// ```
+pub fn remove_introns<S: AsRef<str>>(dna: &str, introns: &[S]) -> String {
+  introns.iter().filter(|intron| !intron.as_ref().is_empty()).fold(dna.to_string(), |dna, intron| {
+    dna.replace(intron.as_ref(), "")
+  })
+}
+
+/// This function builds mature transcript from exons given as zero-based half-open
+/// ranges of DNA string, exons must be ordered and must not overlap
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::splc::*;
+///
+/// assert_eq!(splice_exons("ATGCCCTTTGGGTAA", &[0..3, 6..9, 12..15]).unwrap(), "ATGTTTTAA");
+/// assert_eq!(splice_exons("ATGCCC", &[3..6, 0..3]).unwrap_err(), InvalidInputParameters);
+/// assert_eq!(splice_exons("ATGCCC", &[0..7]).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn splice_exons(dna: &str, exons: &[Range<usize>]) -> RosalindResult<String> {
+  let mut transcript = String::new();
+  let mut previous_end = 0;
+
+  for exon in exons {
+    if exon.start < previous_end { return Err(InvalidInputParameters); }
+    transcript.push_str(try!(dna.get(exon.clone()).ok_or(InvalidInputParameters)));
+    previous_end = exon.end;
+  }
+
+  Ok(transcript)
+}
+
+/// This function removes introns from gene, transcribes it into RNA and translates
+/// into protein string
+///
+/// ## Examples
+/// ```
+/// use rosalind::splc::*;
+///
+/// assert_eq!(splice_and_translate("ATGCCCTTTGGGTAA", &["CCC", "GGG"]).unwrap(), "MF");
+/// ```
+pub fn splice_and_translate<S: AsRef<str>>(gene: &str, introns: &[S]) -> RosalindResult<String> {
+  let rna = try!(transcribe_dna_into_rna(&remove_introns(gene, introns)));
+  translate_rna_into_protein(&rna)
+}
+
+/// This function splices gene given by the first record of FASTA dataset, the
+/// remaining records are introns, and returns translated protein string
+///
+/// ## Examples
+/// ```
+/// use rosalind::splc::*;
+///
+/// let dataset = ">Rosalind_10
+///   ATGGTCTACATAGCTGACAAACAGCACGTAGCAATCGGTCGAATCTCGAGAGGCATATGGTCACATGATCGGTCGAGCGTGTTTCAAAGTTTGCGCCTAG
+///   >Rosalind_12
+///   ATCGGTCGAA
+///   >Rosalind_15
+///   ATCGGTCGAGCGTGT";
+///
+/// assert_eq!(splice_and_translate_dataset(dataset).unwrap(), "MVYIADKQHVASREAYGHMFKVCA");
+/// ```
+pub fn splice_and_translate_dataset(dataset: &str) -> RosalindResult<String> {
+  splice_and_translate_records(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function splices gene given by the first FASTA record, the remaining records
+/// are introns, and returns translated protein string
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::splc::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![
+///   Ok(FastaRecord::new("gene", "", "ATGCCCTTTGGGTAA")),
+///   Ok(FastaRecord::new("intron", "", "CCC")),
+///   Ok(FastaRecord::new("intron", "", "GGG")),
+/// ];
+/// assert_eq!(splice_and_translate_records(records).unwrap(), "MF");
+/// assert_eq!(splice_and_translate_records(vec![]).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn splice_and_translate_records<I>(records: I) -> RosalindResult<String>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let mut records = records.into_iter();
+  let gene = try!(try!(records.next().ok_or(InvalidInputParameters)));
+
+  let mut introns = Vec::new();
+  for record in records {
+    introns.push(try!(record).seq);
+  }
+
+  splice_and_translate(&gene.seq, &introns)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use fasta::FastaRecord;
+  use super::super::RosalindError::{InvalidInputParameters, UnknownNucleotide, FastaMissingLabel};
+
+  const DATASET: &'static str = ">Rosalind_10
+ATGGTCTACATAGCTGACAAACAGCACGTAGCAATCGGTCGAATCTCGAGAGGCATATGGTCACATGATCGGTCGAGCGTGTTTCAAAGTTTGCGCCTAG
+>Rosalind_12
+ATCGGTCGAA
+>Rosalind_15
+ATCGGTCGAGCGTGT
+";
+
+  #[test]
+  fn it_should_splice_and_translate_dataset() {
+    assert_eq!(splice_and_translate_dataset(DATASET).unwrap(), "MVYIADKQHVASREAYGHMFKVCA");
+  }
+
+  #[test]
+  fn it_should_translate_gene_without_introns() {
+    assert_eq!(splice_and_translate_dataset(">Rosalind_1\nATGGCCTAA\n").unwrap(), "MA");
+  }
+
+  #[test]
+  fn it_should_return_error_when_dataset_is_empty() {
+    assert_eq!(splice_and_translate_dataset("").unwrap_err(), InvalidInputParameters);
+  }
+
+  #[test]
+  fn it_should_return_error_when_record_is_malformed() {
+    let records = vec![Ok(FastaRecord::new("gene", "", "ATGTAA")), Err(FastaMissingLabel(3))];
+    assert_eq!(splice_and_translate_records(records).unwrap_err(), FastaMissingLabel(3));
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(splice_and_translate("ATGZZZTAA", &["ZZ"]).unwrap_err(), UnknownNucleotide('Z'));
+  }
+
+  #[test]
+  fn it_should_remove_introns() {
+    assert_eq!(remove_introns("AAACCCAAACCC", &["CCC"]), "AAAAAA");
+    assert_eq!(remove_introns("AAACCC", &[""]), "AAACCC");
+    assert_eq!(remove_introns("AACCGGCCTT", &["CCGGCC", "CC"]), "AATT");
+  }
+
+  #[test]
+  fn it_should_splice_exons() {
+    let gene = "ATGGTCTACATAGCTGACAAACAG";
+    assert_eq!(splice_exons(gene, &[0..6, 12..15, 21..24]).unwrap(), "ATGGTCGCTCAG");
+    assert_eq!(splice_exons(gene, &[0..3, 3..6]).unwrap(), "ATGGTC");
+    assert_eq!(splice_exons(gene, &[]).unwrap(), "");
+  }
+
+  #[test]
+  fn it_should_return_error_when_exons_are_invalid() {
+    let gene = "ATGGTCTACATAGCTGACAAACAG";
+    assert_eq!(splice_exons(gene, &[0..6, 5..9]).unwrap_err(), InvalidInputParameters);
+    assert_eq!(splice_exons(gene, &[6..3]).unwrap_err(), InvalidInputParameters);
+    assert_eq!(splice_exons(gene, &[20..25]).unwrap_err(), InvalidInputParameters);
+  }
+}
@@ -0,0 +1,160 @@
+//! Module for `Finding a Spliced Motif`
+
+use num::{BigUint, Zero, One};
+use RosalindResult;
+use RosalindError::InvalidInputParameters;
+use fasta::{FastaReader, FastaRecord};
+
+/// This function finds the leftmost occurrence of spliced motif (subsequence) `t` in string `s`
+/// and returns 1-based locations of its symbols, `None` is returned when `t` is not
+/// a subsequence of `s`
+///
+/// ## Examples
+/// ```
+/// use rosalind::sseq::*;
+///
+/// assert_eq!(spliced_motif_lookup("ACGTACGTGACG", "GTA"), Some(vec![3, 4, 5]));
+/// assert_eq!(spliced_motif_lookup("ACGTACGTGACG", "TTT"), None);
+/// ```
+pub fn spliced_motif_lookup(s: &str, t: &str) -> Option<Vec<usize>> {
+  let mut indices = Vec::with_capacity(t.len());
+  let mut s_iter = s.bytes().enumerate();
+  for symbol in t.bytes() {
+    match s_iter.find(|&(_, s_symbol)| s_symbol == symbol) {
+      Some((i, _)) => indices.push(i + 1),
+      None => return None,
+    }
+  }
+  Some(indices)
+}
+
+/// This function checks whether `t` is a spliced motif (subsequence) of string `s`
+///
+/// ## Examples
+/// ```
+/// use rosalind::sseq::*;
+///
+/// assert!(is_spliced_motif("ACGTACGTGACG", "GTA"));
+/// assert!(!is_spliced_motif("GTA", "ACGTACGTGACG"));
+/// ```
+pub fn is_spliced_motif(s: &str, t: &str) -> bool {
+  spliced_motif_lookup(s, t).is_some()
+}
+
+/// This function counts all occurrences of spliced motif (subsequence) `t` in string `s`,
+/// i.e. the number of distinct collections of locations of symbols of `t` in `s`
+///
+/// ## Examples
+/// ```
+/// # extern crate num;
+/// # extern crate rosalind;
+/// # fn main() {
+/// use num::bigint::ToBigUint;
+/// use rosalind::sseq::*;
+///
+/// assert_eq!(count_spliced_motifs("ACGTACGTGACG", "GTA"), 4.to_biguint().unwrap());
+/// assert_eq!(count_spliced_motifs("AAAA", "AA"), 6.to_biguint().unwrap());
+/// # }
+/// ```
+pub fn count_spliced_motifs(s: &str, t: &str) -> BigUint {
+  let t = t.as_bytes();
+  // `counts[j]` is the number of occurrences of the first `j` symbols of `t`
+  let mut counts: Vec<BigUint> = vec![Zero::zero(); t.len() + 1];
+  counts[0] = One::one();
+
+  for symbol in s.bytes() {
+    for j in (0..t.len()).rev() {
+      if t[j] == symbol {
+        let count = counts[j].clone();
+        counts[j + 1] = &counts[j + 1] + count;
+      }
+    }
+  }
+
+  counts.pop().unwrap()
+}
+
+/// This function finds spliced motif given by the second record of FASTA dataset
+/// in string given by the first record
+///
+/// ## Examples
+/// ```
+/// use rosalind::sseq::*;
+///
+/// let dataset = ">Rosalind_14
+///   ACGTACGTGACG
+///   >Rosalind_18
+///   GTA";
+///
+/// assert_eq!(spliced_motif_in_dataset(dataset).unwrap(), Some(vec![3, 4, 5]));
+/// ```
+pub fn spliced_motif_in_dataset(dataset: &str) -> RosalindResult<Option<Vec<usize>>> {
+  spliced_motif_in_records(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function finds spliced motif given by the second FASTA record in string given by
+/// the first record, a dataset with any other number of records is rejected
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::sseq::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![Ok(FastaRecord::new("s", "", "GATTACA")), Ok(FastaRecord::new("t", "", "GTC"))];
+/// assert_eq!(spliced_motif_in_records(records).unwrap(), Some(vec![1, 3, 6]));
+/// assert_eq!(spliced_motif_in_records(vec![]).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn spliced_motif_in_records<I>(records: I) -> RosalindResult<Option<Vec<usize>>>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+  if records.len() != 2 { return Err(InvalidInputParameters); }
+  Ok(spliced_motif_lookup(&records[0].seq, &records[1].seq))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use num::bigint::ToBigUint;
+  use num::{BigUint, One};
+  use fasta::FastaRecord;
+  use super::super::RosalindError::{InvalidInputParameters, FastaMissingLabel};
+
+  #[test]
+  fn it_should_find_spliced_motif() {
+    assert_eq!(spliced_motif_lookup("ACGTACGTGACG", "GTA"), Some(vec![3, 4, 5]));
+    assert_eq!(spliced_motif_lookup("ACGTACGTGACG", "ACGTACGTGACG"), Some((1..13).collect()));
+    assert_eq!(spliced_motif_lookup("ACGTACGTGACG", ""), Some(vec![]));
+  }
+
+  #[test]
+  fn it_should_not_find_spliced_motif() {
+    assert_eq!(spliced_motif_lookup("ACGTACGTGACG", "GGGGG"), None);
+    assert_eq!(spliced_motif_lookup("", "A"), None);
+    assert!(!is_spliced_motif("ACGT", "TGCA"));
+  }
+
+  #[test]
+  fn it_should_count_spliced_motifs() {
+    assert_eq!(count_spliced_motifs("ACGTACGTGACG", "GTA"), 4.to_biguint().unwrap());
+    assert_eq!(count_spliced_motifs("ACGT", "TGCA"), 0.to_biguint().unwrap());
+    assert_eq!(count_spliced_motifs("ACGT", ""), 1.to_biguint().unwrap());
+  }
+
+  #[test]
+  fn it_should_count_spliced_motifs_beyond_machine_integers() {
+    // 200 choose 100 occurrences of `A`s
+    let s: String = ::std::iter::repeat('A').take(200).collect();
+    let t: String = ::std::iter::repeat('A').take(100).collect();
+    let expected = (1..101u32).fold(BigUint::one(), |c, k| c * (100 + k).to_biguint().unwrap() / k.to_biguint().unwrap());
+    assert_eq!(count_spliced_motifs(&s, &t), expected);
+  }
+
+  #[test]
+  fn it_should_return_error_when_records_are_not_pair() {
+    let records = vec![Ok(FastaRecord::new("s", "", "ACGT"))];
+    assert_eq!(spliced_motif_in_records(records).unwrap_err(), InvalidInputParameters);
+    let records = vec![Ok(FastaRecord::new("s", "", "ACGT")), Err(FastaMissingLabel(3))];
+    assert_eq!(spliced_motif_in_records(records).unwrap_err(), FastaMissingLabel(3));
+  }
+}
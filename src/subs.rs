@@ -2,6 +2,7 @@
 
 use RosalindResult;
 use RosalindError::MotifStringsLengthError;
+use iupac;
 
 
 /// This function finds locations of substring `t` in string `s` (finds a motif in DNA)
@@ -25,10 +26,36 @@ pub fn motif_lookup(s: &str, t: &str) -> RosalindResult<Vec<usize>> {
   Ok(motif)
 }
 
+/// This function finds locations of degenerate motif `t` with IUPAC ambiguity codes
+/// in DNA string `s`, e.g. `N` in the motif matches any nucleotide
+///
+/// ```
+/// use rosalind::RosalindError::MotifStringsLengthError;
+/// use rosalind::subs::*;
+///
+/// let s = "AGGACCTTGGTCCAGGNCC";
+/// assert_eq!(motif_lookup_iupac(s, "GGNCC").unwrap(), vec![2, 9, 15]);
+/// assert_eq!(motif_lookup_iupac("GG", "GGNCC").unwrap_err(), MotifStringsLengthError);
+/// ```
+pub fn motif_lookup_iupac(s: &str, t: &str) -> RosalindResult<Vec<usize>> {
+  let s_masks: RosalindResult<Vec<u8>> = s.chars().map(iupac::nucleotide_mask).collect();
+  let t_masks: RosalindResult<Vec<u8>> = t.chars().map(iupac::nucleotide_mask).collect();
+  let (s_masks, t_masks) = (try!(s_masks), try!(t_masks));
+
+  let (s_len, t_len) = (s_masks.len(), t_masks.len());
+  if s_len < t_len { return Err(MotifStringsLengthError); }
+  let mut motif: Vec<usize> = Vec::new();
+  for i in 0..(s_len - t_len + 1) {
+    let found = s_masks[i..i + t_len].iter().zip(t_masks.iter()).all(|(s_mask, t_mask)| s_mask & !t_mask == 0);
+    if found { motif.push(i + 1); }
+  }
+  Ok(motif)
+}
+
 #[cfg(test)]
 mod tests {
-  use super::motif_lookup;
-  use super::super::RosalindError::MotifStringsLengthError;
+  use super::{motif_lookup, motif_lookup_iupac};
+  use super::super::RosalindError::{MotifStringsLengthError, UnknownNucleotide};
 
   #[test]
   fn it_should_return_all_locations_of_substring_t_in_s() {
@@ -43,4 +70,23 @@ mod tests {
     let t = "GATATATGCATATACTT";
     assert_eq!(motif_lookup(s, t).unwrap_err(), MotifStringsLengthError);
   }
+
+  #[test]
+  fn it_should_return_all_locations_of_degenerate_motif_t_in_s() {
+    let s = "GATATATGCATATACTT";
+    assert_eq!(motif_lookup_iupac(s, "ATAT").unwrap(), vec![2, 4, 10]);
+    assert_eq!(motif_lookup_iupac(s, "WTAK").unwrap(), vec![2, 4, 10]);
+    assert_eq!(motif_lookup_iupac(s, "NNNNNNNNNNNNNNNNN").unwrap(), vec![1]);
+  }
+
+  #[test]
+  fn it_should_not_match_ambiguous_nucleotide_with_exact_motif() {
+    assert_eq!(motif_lookup_iupac("GGNCCGGACC", "GGACC").unwrap(), vec![6]);
+    assert_eq!(motif_lookup_iupac("GGNCCGGACC", "GGNCC").unwrap(), vec![1, 6]);
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_ambiguity_code_found() {
+    assert_eq!(motif_lookup_iupac("GGACC", "GZ").unwrap_err(), UnknownNucleotide('Z'));
+  }
 }
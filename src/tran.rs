@@ -0,0 +1,153 @@
+//! Module for `Transitions and Transversions`
+
+use RosalindResult;
+use RosalindError::{HammingStringsLengthError, InvalidInputParameters};
+use fasta::{FastaReader, FastaRecord};
+use seq::{DnaBase, DnaSeq};
+
+/// This structure contains numbers of transitions and transversions between two DNA strings
+#[derive(PartialEq, Debug)]
+pub struct PointMutations {
+  pub transitions: usize,
+  pub transversions: usize,
+}
+
+impl PointMutations {
+  /// This function returns transition/transversion ratio, the ratio is infinite when
+  /// there are no transversions and NaN when there are no point mutations at all
+  ///
+  /// ## Examples
+  /// ```
+  /// use rosalind::tran::*;
+  ///
+  /// assert_eq!(PointMutations {transitions: 3, transversions: 2}.ratio(), 1.5);
+  /// assert!(PointMutations {transitions: 0, transversions: 0}.ratio().is_nan());
+  /// ```
+  pub fn ratio(&self) -> f64 {
+    self.transitions as f64 / self.transversions as f64
+  }
+}
+
+fn is_purine(base: DnaBase) -> bool {
+  base == DnaBase::A || base == DnaBase::G
+}
+
+/// This function classifies point mutations between DNA strings `s` and `t` as
+/// transitions (A <-> G, C <-> T) or transversions, nucleotides are case-insensitive
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::HammingStringsLengthError;
+/// use rosalind::tran::*;
+///
+/// let mutations = transitions_and_transversions("GATTACA", "AACTAGA").unwrap();
+/// assert_eq!(mutations, PointMutations {transitions: 2, transversions: 1});
+/// assert_eq!(mutations.ratio(), 2.0);
+/// assert_eq!(transitions_and_transversions("G", "").unwrap_err(), HammingStringsLengthError);
+/// ```
+pub fn transitions_and_transversions(s: &str, t: &str) -> RosalindResult<PointMutations> {
+  let s = try!(DnaSeq::new(s));
+  let t = try!(DnaSeq::new(t));
+  if s.len() != t.len() { return Err(HammingStringsLengthError); }
+
+  let mut mutations = PointMutations {transitions: 0, transversions: 0};
+  for (&sb, &tb) in s.bases().iter().zip(t.bases()) {
+    if sb == tb { continue; }
+    if is_purine(sb) == is_purine(tb) {
+      mutations.transitions += 1;
+    } else {
+      mutations.transversions += 1;
+    }
+  }
+
+  Ok(mutations)
+}
+
+/// This function classifies point mutations between two DNA strings in FASTA dataset
+///
+/// ## Examples
+/// ```
+/// use rosalind::tran::*;
+///
+/// let dataset = ">Rosalind_0209
+///   GCAACGCACAACGAAAACCCTTAGGGACTGGATTATTTCGTGATCGTTGTAGTTATTGGAAGTACGGGCATCAACCCAGTT
+///   >Rosalind_2200
+///   TTATCTGACAAAGAAAGCCGTCAACGGCTGGATAATTTCGCGATCGTGCTGGTTACTGGCGGTACGAGTGTTCCTTTGGGT";
+///
+/// let mutations = transitions_and_transversions_in_dataset(dataset).unwrap();
+/// assert_eq!(format!("{:.11}", mutations.ratio()), "1.21428571429");
+/// ```
+pub fn transitions_and_transversions_in_dataset(dataset: &str) -> RosalindResult<PointMutations> {
+  transitions_and_transversions_in_records(FastaReader::new(dataset.as_bytes()))
+}
+
+/// This function classifies point mutations between sequences of a pair of FASTA records,
+/// `InvalidInputParameters` is returned unless exactly two records are given
+///
+/// ## Examples
+/// ```
+/// use rosalind::RosalindError::InvalidInputParameters;
+/// use rosalind::tran::*;
+/// use rosalind::fasta::FastaRecord;
+///
+/// let records = vec![Ok(FastaRecord::new("1", "", "ACGT")), Ok(FastaRecord::new("2", "", "GCTT"))];
+/// assert_eq!(transitions_and_transversions_in_records(records).unwrap(),
+///   PointMutations {transitions: 1, transversions: 1});
+/// assert_eq!(transitions_and_transversions_in_records(vec![]).unwrap_err(), InvalidInputParameters);
+/// ```
+pub fn transitions_and_transversions_in_records<I>(records: I) -> RosalindResult<PointMutations>
+  where I: IntoIterator<Item = RosalindResult<FastaRecord>> {
+  let records: Vec<FastaRecord> = try!(records.into_iter().collect());
+  if records.len() != 2 { return Err(InvalidInputParameters); }
+  transitions_and_transversions(&records[0].seq, &records[1].seq)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use fasta::FastaRecord;
+  use super::super::RosalindError::{HammingStringsLengthError, InvalidInputParameters, UnknownNucleotide};
+
+  #[test]
+  fn it_should_count_transitions_and_transversions() {
+    let mutations = transitions_and_transversions("AGCT", "GATC").unwrap();
+    assert_eq!(mutations, PointMutations {transitions: 4, transversions: 0});
+    let mutations = transitions_and_transversions("AAGG", "CTCT").unwrap();
+    assert_eq!(mutations, PointMutations {transitions: 0, transversions: 4});
+  }
+
+  #[test]
+  fn it_should_count_mutations_case_insensitive() {
+    assert_eq!(transitions_and_transversions("acgt", "GCGA").unwrap(), PointMutations {transitions: 1, transversions: 1});
+  }
+
+  #[test]
+  fn it_should_return_ratio() {
+    let s = "GCAACGCACAACGAAAACCCTTAGGGACTGGATTATTTCGTGATCGTTGTAGTTATTGGAAGTACGGGCATCAACCCAGTT";
+    let t = "TTATCTGACAAAGAAAGCCGTCAACGGCTGGATAATTTCGCGATCGTGCTGGTTACTGGCGGTACGAGTGTTCCTTTGGGT";
+    let mutations = transitions_and_transversions(s, t).unwrap();
+    assert_eq!(mutations, PointMutations {transitions: 17, transversions: 14});
+    assert!((mutations.ratio() - 1.21428571429).abs() < 1e-9);
+    assert!(PointMutations {transitions: 1, transversions: 0}.ratio().is_infinite());
+  }
+
+  #[test]
+  fn it_should_return_error_when_strings_have_different_length() {
+    assert_eq!(transitions_and_transversions("ACGT", "ACG").unwrap_err(), HammingStringsLengthError);
+  }
+
+  #[test]
+  fn it_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(transitions_and_transversions("ACGU", "ACGT").unwrap_err(), UnknownNucleotide('U'));
+  }
+
+  #[test]
+  fn it_should_return_error_when_records_are_not_pair() {
+    let records = vec![
+      Ok(FastaRecord::new("1", "", "A")),
+      Ok(FastaRecord::new("2", "", "G")),
+      Ok(FastaRecord::new("3", "", "T")),
+    ];
+    assert_eq!(transitions_and_transversions_in_records(records).unwrap_err(), InvalidInputParameters);
+  }
+}
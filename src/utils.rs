@@ -1,11 +1,8 @@
 //! Module with useful utilities
 
+use std::ops::Range;
 use RosalindResult;
-use constants::FASTA_LABEL_SYMBOL;
-
-fn is_fasta_label(s: &str) -> bool {
-    s.contains(FASTA_LABEL_SYMBOL)
-}
+use fasta::FastaReader;
 
 /// This parses dataset in FASTA format into array of DNA strings
 ///
@@ -32,31 +29,59 @@ fn is_fasta_label(s: &str) -> bool {
 /// assert_eq!(parse_fasta_dataset(fasta_dataset).unwrap(), expected_dataset);
 /// ```
 pub fn parse_fasta_dataset(dataset: &str) -> RosalindResult<Vec<String>> {
-    let delimiter: char = '\x00';
-    let mut buf: String = String::new();
+    FastaReader::new(dataset.as_bytes())
+        .map(|record| record.map(|r| r.seq))
+        .collect()
+}
 
-    for dataset_line in dataset.lines() {
-        if is_fasta_label(dataset_line) {
-            if !buf.is_empty() {
-                buf.push(delimiter);
-            }
-        } else {
-            buf.push_str(dataset_line.trim());
+/// This function reports soft-masked (lowercase) intervals of the sequence as
+/// zero-based half-open ranges, `\n` symbols are skipped
+///
+/// ## Examples
+/// ```
+/// use rosalind::utils::*;
+///
+/// assert_eq!(soft_masked_intervals("ACgtaCCnnnT"), vec![2..5, 7..10]);
+/// assert_eq!(soft_masked_intervals("ACGT"), vec![]);
+/// ```
+pub fn soft_masked_intervals(seq: &str) -> Vec<Range<usize>> {
+    let mut intervals: Vec<Range<usize>> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut len: usize = 0;
+
+    for symbol in seq.chars().filter(|&symbol| symbol != '\n') {
+        if symbol.is_lowercase() {
+            if start.is_none() { start = Some(len); }
+        } else if let Some(interval_start) = start.take() {
+            intervals.push(interval_start..len);
         }
+        len += 1;
+    }
+
+    if let Some(interval_start) = start {
+        intervals.push(interval_start..len);
     }
 
-    Ok(buf.split(delimiter).map(|s| s.to_string()).collect())
+    intervals
+}
+
+// reproducible pseudorandom numbers for tests, `seed` is advanced by linear congruential generator
+#[cfg(test)]
+pub fn next_random(seed: &mut u32) -> usize {
+    *seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+    (*seed >> 16) as usize
 }
 
-#[test]
-fn it_should_determine_fasta_label() {
-    assert_eq!(is_fasta_label(">Rosalind_1"), true);
-    assert_eq!(is_fasta_label("CCTGCGGAAG"), false);
+// reproducible pseudorandom string of symbols of `alphabet` for tests
+#[cfg(test)]
+pub fn random_string(alphabet: &str, len: usize, seed: &mut u32) -> String {
+    let alphabet = alphabet.as_bytes();
+    (0..len).map(|_| alphabet[next_random(seed) % alphabet.len()] as char).collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::parse_fasta_dataset;
+    use super::{parse_fasta_dataset, soft_masked_intervals};
 
     #[test]
     fn it_should_parse_fasta_dataset() {
@@ -80,4 +105,18 @@ mod tests {
 
         assert_eq!(dataset, expected_dataset);
     }
+
+    #[test]
+    fn it_should_parse_empty_and_unlabeled_datasets() {
+        assert_eq!(parse_fasta_dataset("").unwrap(), Vec::<String>::new());
+        assert_eq!(parse_fasta_dataset("ACGT\nACGT").unwrap(), vec!["ACGTACGT"]);
+        assert_eq!(parse_fasta_dataset("ACGT\n>Rosalind_1\nCCAT").unwrap(), vec!["ACGT", "CCAT"]);
+    }
+
+    #[test]
+    fn it_should_report_soft_masked_intervals() {
+        assert_eq!(soft_masked_intervals("acGTacgtACgt"), vec![0..2, 4..8, 10..12]);
+        assert_eq!(soft_masked_intervals("ac\ngtAC\n"), vec![0..4]);
+        assert_eq!(soft_masked_intervals(""), vec![]);
+    }
 }
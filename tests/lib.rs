@@ -13,6 +13,24 @@ use rosalind::gc::*;
 use rosalind::iprb::*;
 use rosalind::cons::*;
 use rosalind::utils::*;
+use rosalind::fasta::*;
+use rosalind::fastq::*;
+use rosalind::seq::*;
+use rosalind::packed::*;
+use rosalind::orf::*;
+use rosalind::splc::*;
+use rosalind::restriction::*;
+use rosalind::grph::*;
+use rosalind::lcsm::*;
+use rosalind::long::*;
+use rosalind::corr::*;
+use rosalind::tran::*;
+use rosalind::sseq::*;
+use rosalind::lcsq::*;
+use rosalind::edit::*;
+use rosalind::align::*;
+use rosalind::mult::*;
+use rosalind::iupac;
 
 use num::{BigUint};
 use num::bigint::{ToBigUint};
@@ -41,6 +59,18 @@ fn dna_should_skip_new_line_symbol() {
     assert_eq!(count_dna_nucleotides("\n").unwrap(), DNANucleotides {A: 0, C: 0, G: 0, T: 0});
 }
 
+#[test]
+fn dna_should_count_dna_nucleotides_with_ambiguity_codes() {
+    let dna = "NNNNAGCTRYSWKMBDHVNNNN";
+    let dna_nucleotides = IupacDNANucleotides {A: 1, C: 1, G: 1, T: 1, ambiguous: 18};
+    assert_eq!(count_dna_nucleotides_iupac(dna).unwrap(), dna_nucleotides);
+}
+
+#[test]
+fn dna_should_count_lowercase_dna_nucleotides() {
+    assert_eq!(count_dna_nucleotides("ACgtacGT").unwrap(), DNANucleotides {A: 2, C: 2, G: 2, T: 2});
+}
+
 // RNA ============================================================================================
 #[test]
 fn rna_should_return_error_when_unknown_nucleotide_found() {
@@ -58,6 +88,17 @@ fn rna_should_skip_new_line_symbol() {
     assert_eq!(transcribe_dna_into_rna("\n").unwrap(), "");
 }
 
+#[test]
+fn rna_should_transcribe_dna_with_ambiguity_codes_into_rna() {
+    assert_eq!(transcribe_dna_into_rna_iupac("NNGATRYSWKMBDHVT").unwrap(), "NNGAURYSWKMBDHVU");
+}
+
+#[test]
+fn rna_should_transcribe_soft_masked_dna_into_rna() {
+    assert_eq!(transcribe_dna_into_rna("GATggaactt").unwrap(), "GAUGGAACUU");
+    assert_eq!(transcribe_dna_into_rna_soft_masked("GATggaactt").unwrap(), "GAUggaacuu");
+}
+
 // REVC ===========================================================================================
 #[test]
 fn revc_should_return_error_when_unknown_nucleotide_found() {
@@ -75,6 +116,24 @@ fn revc_should_skip_new_line_symbol() {
     assert_eq!(reverse_complement_dna("\n").unwrap(), "");
 }
 
+#[test]
+fn revc_should_reverse_complement_dna_with_ambiguity_codes() {
+    assert_eq!(reverse_complement_dna_iupac("ACGTRYSWKMBDHVN").unwrap(), "NBDHVKMWSRYACGT");
+}
+
+#[test]
+fn revc_should_reverse_complement_soft_masked_dna() {
+    assert_eq!(reverse_complement_dna("AAAAcccGGT").unwrap(), "ACCGGGTTTT");
+    assert_eq!(reverse_complement_dna_soft_masked("AAAAcccGGT").unwrap(), "ACCgggTTTT");
+}
+
+#[test]
+fn revc_should_find_reverse_palindromes() {
+    let palindromes = reverse_palindromes("TCAATGCATGCGGGTCTATATGCAT").unwrap();
+    let palindromes: Vec<String> = palindromes.iter().map(|p| p.to_string()).collect();
+    assert_eq!(palindromes, vec!["4 6", "5 4", "6 6", "7 4", "17 4", "18 4", "20 6", "21 4"]);
+}
+
 // FIB ============================================================================================
 #[test]
 fn fib_should_return_recurrence_relation() {
@@ -111,6 +170,11 @@ fn prot_should_return_error_when_unknown_codon_found() {
     assert_eq!(translate_rna_into_protein("ZZZ").unwrap_err(), UnknownCodon("ZZZ".to_string()));
 }
 
+#[test]
+fn prot_should_translate_lowercase_rna_into_protein() {
+    assert_eq!(translate_rna_into_protein("augGCCuga").unwrap(), "MA");
+}
+
 #[test]
 fn prot_should_return_number_of_rna_from_protein() {
     assert_eq!(get_number_of_rna_from_protein("MA").unwrap(), 12);
@@ -141,6 +205,32 @@ fn prot_should_not_calculate_protein_mass() {
     assert_eq!(get_protein_mass("AB").unwrap_err(), UnknownAminoAcid('B'));
 }
 
+#[test]
+fn prot_should_translate_rna_with_alternative_genetic_code() {
+    let rna = "AUGAUAAGAUGAUAA";
+    assert_eq!(translate_rna_into_protein(rna).unwrap(), "MIR");
+    assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::standard()).unwrap(), "MIR");
+    assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::new(2).unwrap()).unwrap(), "MM");
+    assert_eq!(translate_rna_into_protein_with_code(rna, &GeneticCode::new(13).unwrap()).unwrap(), "MMGW");
+}
+
+#[test]
+fn prot_should_find_genetic_codes() {
+    let dna = "ATGGCCATGGCGCCCAGAACTGAGATCAATAGTACCCGTATTAACGGGTGA";
+    assert!(find_genetic_codes(dna, "MAMAPRTEINSTRING").unwrap().contains(&1));
+    assert_eq!(GeneticCode::new(17).unwrap_err(), UnknownGeneticCode(17));
+}
+
+#[test]
+fn prot_should_translate_six_frames() {
+    let dna = "ATGGCCATGGCGCCCAGAACTGAGATCAATAGTACCCGTATTAACGGGTGA";
+    let frames = translate_six_frames(dna, &GeneticCode::standard()).unwrap();
+    assert_eq!(frames.len(), 6);
+    assert_eq!(frames[0].protein, "MAMAPRTEINSTRING*");
+    assert_eq!(frames[3].protein, translate_frame(&reverse_complement_dna(dna).unwrap(), &GeneticCode::standard()).unwrap());
+    assert_eq!(frames[5].strand, Strand::Reverse);
+}
+
 // HAMM ===========================================================================================
 #[test]
 fn hamm_should_return_hamming_distance() {
@@ -169,6 +259,12 @@ fn subs_should_return_error_when_substring_t_is_longer_than_s() {
     assert_eq!(motif_lookup(s, t).unwrap_err(), MotifStringsLengthError);
 }
 
+#[test]
+fn subs_should_return_all_locations_of_degenerate_motif_t_in_s() {
+    let s = "AGGACCTTGGTCCAGGNCC";
+    assert_eq!(motif_lookup_iupac(s, "GGNCC").unwrap(), vec![2, 9, 15]);
+}
+
 // GC =============================================================================================
 #[test]
 fn gc_should_return_0_for_empty_dna_string() {
@@ -180,6 +276,16 @@ fn gc_should_calculate_gc_content_of_dna() {
     assert_eq!(gc_content("AGCTATAG").unwrap(), 37.5f32);
 }
 
+#[test]
+fn gc_should_calculate_gc_content_of_soft_masked_dna() {
+    assert_eq!(gc_content("agctATAG").unwrap(), 37.5f32);
+}
+
+#[test]
+fn gc_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(gc_content("AGCZ").unwrap_err(), UnknownNucleotide('Z'));
+}
+
 #[test]
 fn gc_should_calculate_best_gc_content() {
     let dataset = ">Rosalind_6404
@@ -196,6 +302,15 @@ fn gc_should_calculate_best_gc_content() {
     GCcontent {string_id: "Rosalind_0808".to_string(), gc_content: 60.919540f32});
 }
 
+#[test]
+fn gc_should_calculate_best_gc_content_in_records() {
+    let dataset = ">Rosalind_1\nAGCTATAG\n>Rosalind_2\nGGCCTATA\n";
+    let records = FastaReader::new(dataset.as_bytes());
+
+    assert_eq!(best_gc_content_in_records(records).unwrap(),
+    GCcontent {string_id: "Rosalind_2".to_string(), gc_content: 50f32});
+}
+
 // IPRB ===========================================================================================
 #[test]
 fn iprb_should_retutn_error_when_invalid_input_parameters_provided() {
@@ -253,6 +368,21 @@ fn cons_should_calculate_consensus() {
     assert_eq!(cens, expected_consensus);
 }
 
+#[test]
+fn cons_should_calculate_profile_from_records() {
+    let dataset = ">Rosalind_1\nATCC\n>Rosalind_2\nGGGC\n>Rosalind_3\nATGG\n";
+
+    let expected_profile = Profile {
+        A: vec![2, 0, 0, 0],
+        C: vec![0, 0, 1, 2],
+        G: vec![1, 1, 2, 1],
+        T: vec![0, 2, 0, 0],
+    };
+
+    let prof = profile_from_records(FastaReader::new(dataset.as_bytes())).unwrap();
+    assert_eq!(prof, expected_profile);
+}
+
 // UTILS ==========================================================================================
 #[test]
 fn utils_should_parse_fasta_dataset() {
@@ -276,3 +406,419 @@ fn utils_should_parse_fasta_dataset() {
 
     assert_eq!(dataset, expected_dataset)
 }
+
+#[test]
+fn utils_should_report_soft_masked_intervals() {
+    assert_eq!(soft_masked_intervals("acGTacgtACgt"), vec![0..2, 4..8, 10..12]);
+}
+
+// FASTA ==========================================================================================
+#[test]
+fn fasta_should_read_fasta_records() {
+    let dataset = ">Rosalind_1 Homo sapiens
+        CCTGCGGAAG
+        TCCCACTAAT
+        >Rosalind_2
+        CCATCGGTAG
+        ATATCCATTT";
+
+    let records: Vec<FastaRecord> = FastaReader::new(dataset.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(records, vec![
+        FastaRecord::new("Rosalind_1", "Homo sapiens", "CCTGCGGAAGTCCCACTAAT"),
+        FastaRecord::new("Rosalind_2", "", "CCATCGGTAGATATCCATTT"),
+    ]);
+}
+
+#[test]
+fn fasta_should_write_fasta_records() {
+    let mut writer = FastaWriter::with_line_width(Vec::new(), Some(4));
+    writer.write_record(&FastaRecord::new("Rosalind_1", "first record", "CCTGCGGAAG")).unwrap();
+    writer.write_record(&FastaRecord::new("Rosalind_2", "", "CCAT")).unwrap();
+
+    let dataset = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(dataset, ">Rosalind_1 first record\nCCTG\nCGGA\nAG\n>Rosalind_2\nCCAT\n");
+}
+
+#[test]
+fn fasta_should_keep_labels_after_transformation() {
+    let dataset = ">Rosalind_1 first record\nAAAACCCGGT\n>Rosalind_2\nCCAT\n";
+
+    let mut writer = FastaWriter::new(Vec::new());
+    for record in FastaReader::new(dataset.as_bytes()) {
+        let mut record = record.unwrap();
+        record.seq = reverse_complement_dna(&record.seq).unwrap();
+        writer.write_record(&record).unwrap();
+    }
+
+    let output = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(output, ">Rosalind_1 first record\nACCGGGTTTT\n>Rosalind_2\nATGG\n");
+}
+
+#[test]
+fn fasta_should_return_error_on_malformed_dataset_in_strict_mode() {
+    let mut reader = FastaReader::strict("CCTG\n>Rosalind_1\nCCAT".as_bytes());
+    assert_eq!(reader.next().unwrap().unwrap_err(), FastaMissingLabel(1));
+
+    let mut reader = FastaReader::strict(">Rosalind_1\n>Rosalind_2\nCCAT".as_bytes());
+    assert_eq!(reader.next().unwrap().unwrap_err(), FastaEmptyRecord("Rosalind_1".to_string(), 1));
+
+    let mut reader = FastaReader::strict(">Rosalind_1\nCCTG\n>Rosalind_1\nCCAT".as_bytes());
+    assert_eq!(reader.next().unwrap().unwrap(), FastaRecord::new("Rosalind_1", "", "CCTG"));
+    assert_eq!(reader.next().unwrap().unwrap_err(), FastaDuplicateId("Rosalind_1".to_string(), 3));
+
+    let mut reader = FastaReader::strict(">Rosalind_1\nCCTG\n  CC1G".as_bytes());
+    assert_eq!(reader.next().unwrap().unwrap_err(), FastaIllegalCharacter('1', 3, 5));
+}
+
+// FASTQ ==========================================================================================
+#[test]
+fn fastq_should_read_fastq_records() {
+    let dataset = "@read_1 lane 1\nAGCTATAG\n+\nIIIIIIII\n@read_2\nGGCC\n+read_2\n!!II\n";
+    let records: Vec<FastqRecord> = FastqReader::new(dataset.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(records, vec![
+        FastqRecord::new("read_1", "lane 1", "AGCTATAG", "IIIIIIII"),
+        FastqRecord::new("read_2", "", "GGCC", "!!II"),
+    ]);
+    assert_eq!(count_dna_nucleotides(&records[0].seq).unwrap(), DNANucleotides {A: 3, C: 1, G: 2, T: 2});
+    assert_eq!(gc_content(&records[1].seq).unwrap(), 100f32);
+}
+
+#[test]
+fn fastq_should_decode_quality_scores() {
+    let record = FastqRecord::new("read_1", "", "ACGTA", "!+5?I");
+    assert_eq!(record.quality_scores(QualityEncoding::Phred33).unwrap(), vec![0, 10, 20, 30, 40]);
+    assert_eq!(decode_quality("@JT^h", QualityEncoding::Phred64).unwrap(), vec![0, 10, 20, 30, 40]);
+}
+
+#[test]
+fn fastq_should_return_error_when_lengths_differ() {
+    let mut reader = FastqReader::new("@read_1\nAGCT\n+\nIII\n".as_bytes());
+    assert_eq!(reader.next().unwrap().unwrap_err(), FastqQualityLengthError("read_1".to_string(), 1));
+}
+
+#[test]
+fn fastq_should_write_fastq_records() {
+    let mut writer = FastqWriter::new(Vec::new());
+    writer.write_record(&FastqRecord::new("read_1", "lane 1", "ACGT", "!+5?")).unwrap();
+
+    let dataset = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(dataset, "@read_1 lane 1\nACGT\n+\n!+5?\n");
+}
+
+// SEQ ============================================================================================
+#[test]
+fn seq_should_return_error_when_unknown_nucleotide_found() {
+    assert_eq!(DnaSeq::new("ACGZ").unwrap_err(), UnknownNucleotide('Z'));
+    assert_eq!(RnaSeq::new("ACGT").unwrap_err(), UnknownNucleotide('T'));
+}
+
+#[test]
+fn seq_should_offer_nucleotide_functions_as_methods() {
+    let dna = DnaSeq::new("GATGGAACTTGACTACGTAAATT").unwrap();
+    assert_eq!(dna.count(), DNANucleotides {A: 8, C: 3, G: 5, T: 7});
+    assert_eq!(dna.transcribe().to_string(), transcribe_dna_into_rna("GATGGAACTTGACTACGTAAATT").unwrap());
+    assert_eq!(dna.reverse_complement().to_string(), reverse_complement_dna("GATGGAACTTGACTACGTAAATT").unwrap());
+    assert_eq!(dna.gc_content(), gc_content("GATGGAACTTGACTACGTAAATT").unwrap());
+}
+
+// IUPAC ==========================================================================================
+#[test]
+fn iupac_should_complement_ambiguity_codes() {
+    let complements: String = "ACGTRYSWKMBDHVN".chars().map(|c| iupac::complement(c).unwrap()).collect();
+    assert_eq!(complements, "TGCAYRSWMKVHDBN");
+}
+
+#[test]
+fn iupac_should_match_degenerate_code() {
+    assert_eq!(iupac::matches('N', 'G').unwrap(), true);
+    assert_eq!(iupac::matches('R', 'C').unwrap(), false);
+    assert_eq!(iupac::matches('A', 'N').unwrap(), false);
+}
+
+// PACKED =========================================================================================
+#[test]
+fn packed_should_convert_to_and_from_dna_string() {
+    let dna = "GATGGAACTTGACTACGTAAATTNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNNGATGGAACTTGACTACGTAAATT";
+    let packed_dna = PackedDna::new(dna).unwrap();
+    assert_eq!(packed_dna.to_string(), dna);
+    assert_eq!(packed_dna.n_runs(), &[23..63]);
+    assert_eq!(PackedDna::from(&DnaSeq::new("GATTACA").unwrap()).to_string(), "GATTACA");
+}
+
+#[test]
+fn packed_should_reverse_complement_dna() {
+    let dna = "GATGGAACTTGACTACGTAAATTGATGGAACTTGACTACGTAAATT";
+    let packed_dna = PackedDna::new(dna).unwrap();
+    assert_eq!(reverse_complement_packed_dna(&packed_dna).to_string(), reverse_complement_dna(dna).unwrap());
+}
+
+#[test]
+fn packed_should_return_hamming_distance() {
+    let s = "GAGCCTACTAACGGGATGAGCCTACTAACGGGATGAGCCTACTAACGGGAT";
+    let t = "CATCGTAATGACGGCCTCATCGTAATGACGGCCTCATCGTAATGACGGCCT";
+    let (packed_s, packed_t) = (PackedDna::new(s).unwrap(), PackedDna::new(t).unwrap());
+    assert_eq!(hamming_distance_packed(&packed_s, &packed_t).unwrap(), hamming_distance(s, t).unwrap() as usize);
+}
+
+// ORF ============================================================================================
+#[test]
+fn orf_should_return_distinct_proteins() {
+    let dna = "AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG";
+    let mut proteins = orf_proteins(dna, &OrfOptions::default()).unwrap();
+    proteins.sort();
+    assert_eq!(proteins, vec!["M", "MGMTPRLGLESLLE", "MLLGSFRLIPKETLIQVAGSSPCNLS", "MTPRLGLESLLE"]);
+}
+
+#[test]
+fn orf_should_return_locations_on_forward_strand() {
+    let dna = "CCATGAAATAGTTACATTT";
+    let orfs = find_orfs(dna, &OrfOptions::default()).unwrap();
+    assert_eq!(orfs.len(), 2);
+    assert_eq!(&dna[orfs[0].location.clone()], "ATGAAATAG");
+    assert_eq!(reverse_complement_dna(&dna[orfs[1].location.clone()]).unwrap(), "ATGTAA");
+}
+
+// SPLC ===========================================================================================
+#[test]
+fn splc_should_splice_and_translate_dataset() {
+    let dataset = ">Rosalind_10
+        ATGGTCTACATAGCTGACAAACAGCACGTAGCAATCGGTCGAATCTCGAGAGGCATATGGTCACATGATCGGTCGAGCGTGTTTCAAAGTTTGCGCCTAG
+        >Rosalind_12
+        ATCGGTCGAA
+        >Rosalind_15
+        ATCGGTCGAGCGTGT";
+    assert_eq!(splice_and_translate_dataset(dataset).unwrap(), "MVYIADKQHVASREAYGHMFKVCA");
+    assert_eq!(splice_and_translate_records(FastaReader::new(dataset.as_bytes())).unwrap(), "MVYIADKQHVASREAYGHMFKVCA");
+}
+
+#[test]
+fn splc_should_splice_exons() {
+    let gene = "ATGGTCTACATAGCTGACAAACAG";
+    let transcript = splice_exons(gene, &[0..6, 12..15, 21..24]).unwrap();
+    assert_eq!(translate_rna_into_protein(&transcribe_dna_into_rna(&transcript).unwrap()).unwrap(), "MVAQ");
+    assert_eq!(splice_exons(gene, &[3..6, 0..3]).unwrap_err(), InvalidInputParameters);
+}
+
+// RESTRICTION ====================================================================================
+#[test]
+fn restriction_should_digest_dna() {
+    let dna = "TTGAATTCGGATCCAAGCTTCC";
+    let enzymes: Vec<RestrictionEnzyme> = ["EcoRI", "BamHI", "HindIII"].iter().map(|name| restriction_enzyme(name).unwrap()).collect();
+    assert_eq!(digest(dna, &enzymes).unwrap(), vec![3, 6, 6, 7]);
+    assert_eq!(restriction_enzyme("Foo").unwrap_err(), UnknownRestrictionEnzyme("Foo".to_string()));
+}
+
+// GRPH ===========================================================================================
+#[test]
+fn grph_should_build_overlap_graph() {
+    let dataset = ">Rosalind_0498
+        AAATAAA
+        >Rosalind_2391
+        AAATTTT
+        >Rosalind_2323
+        TTTTCCC
+        >Rosalind_0442
+        AAATCCC
+        >Rosalind_5013
+        GGGTGGG";
+    let edges: Vec<String> = overlap_graph_in_dataset(dataset, 3).unwrap().iter().map(|&(ref s, ref t)| format!("{} {}", s, t)).collect();
+    assert_eq!(edges, vec!["Rosalind_0498 Rosalind_2391", "Rosalind_0498 Rosalind_0442", "Rosalind_2391 Rosalind_2323"]);
+    assert_eq!(overlap_graph(FastaReader::new(dataset.as_bytes()), 5).unwrap(), vec![]);
+}
+
+// LCSM ===========================================================================================
+#[test]
+fn lcsm_should_find_shared_motif() {
+    let dataset = ">Rosalind_1
+        GATTACA
+        >Rosalind_2
+        TAGACCA
+        >Rosalind_3
+        ATACA";
+    let motif = shared_motif_in_dataset(dataset).unwrap();
+    assert_eq!(motif.len(), 2);
+    assert!(parse_fasta_dataset(dataset).unwrap().iter().all(|dna| dna.contains(&*motif)));
+}
+
+// LONG ===========================================================================================
+#[test]
+fn long_should_assemble_shortest_superstring() {
+    let dataset = ">Rosalind_56
+        ATTAGACCTG
+        >Rosalind_57
+        CCTGCCGGAA
+        >Rosalind_58
+        AGACCTGCCG
+        >Rosalind_59
+        GCCGGAATAC";
+    let assembly = assemble_dataset(dataset).unwrap();
+    assert_eq!(assembly, Assembly {superstring: "ATTAGACCTGCCGGAATAC".to_string(), unplaced: vec![]});
+    assert!(parse_fasta_dataset(dataset).unwrap().iter().all(|read| assembly.superstring.contains(&**read)));
+}
+
+// CORR ===========================================================================================
+#[test]
+fn corr_should_correct_reads() {
+    let dataset = ">Rosalind_52
+        TCATC
+        >Rosalind_44
+        TTCAT
+        >Rosalind_68
+        TCATC
+        >Rosalind_28
+        TGAAA
+        >Rosalind_95
+        GAGGA
+        >Rosalind_66
+        TTTCA
+        >Rosalind_33
+        ATCAA
+        >Rosalind_21
+        TTGAT
+        >Rosalind_18
+        TTTCC";
+    let corrections = correct_reads_in_dataset(dataset).unwrap();
+    let ids: Vec<&str> = corrections.iter().map(|correction| &*correction.id).collect();
+    assert_eq!(ids, vec!["Rosalind_44", "Rosalind_95", "Rosalind_18"]);
+    let output: Vec<String> = corrections.iter().map(|correction| correction.to_string()).collect();
+    assert_eq!(output, vec!["TTCAT->TTGAT", "GAGGA->GATGA", "TTTCC->TTTCA"]);
+}
+
+// TRAN ===========================================================================================
+#[test]
+fn tran_should_return_transition_transversion_ratio() {
+    let dataset = ">Rosalind_0209
+        GCAACGCACAACGAAAACCCTTAGGGACTGGATTATTTCGTGATCGTTGTAGTTATTGGAAGTACGGGCATCAACCCAGTT
+        >Rosalind_2200
+        TTATCTGACAAAGAAAGCCGTCAACGGCTGGATAATTTCGCGATCGTGCTGGTTACTGGCGGTACGAGTGTTCCTTTGGGT";
+    let mutations = transitions_and_transversions_in_dataset(dataset).unwrap();
+    assert_eq!(mutations, PointMutations {transitions: 17, transversions: 14});
+    assert_eq!(format!("{:.11}", mutations.ratio()), "1.21428571429");
+    assert_eq!(transitions_and_transversions("ACGT", "AC").unwrap_err(), HammingStringsLengthError);
+}
+
+// SSEQ ===========================================================================================
+#[test]
+fn sseq_should_find_spliced_motif() {
+    let dataset = ">Rosalind_14
+        ACGTACGTGACG
+        >Rosalind_18
+        GTA";
+    let indices = spliced_motif_in_dataset(dataset).unwrap().unwrap();
+    assert_eq!(indices, vec![3, 4, 5]);
+    let s = "ACGTACGTGACG".as_bytes();
+    assert_eq!(indices.iter().map(|&i| s[i - 1] as char).collect::<String>(), "GTA");
+    assert_eq!(count_spliced_motifs("ACGTACGTGACG", "GTA").to_string(), "4");
+}
+
+// LCSQ ===========================================================================================
+#[test]
+fn lcsq_should_find_longest_common_subsequence() {
+    let dataset = ">Rosalind_23
+        AACCTTGG
+        >Rosalind_64
+        ACACTGTGA";
+    let lcs = longest_common_subsequence_in_dataset(dataset).unwrap();
+    assert_eq!(lcs.subsequence.len(), 6);
+    assert!(is_spliced_motif("AACCTTGG", &lcs.subsequence));
+    assert!(is_spliced_motif("ACACTGTGA", &lcs.subsequence));
+}
+
+// EDIT ===========================================================================================
+#[test]
+fn edit_should_return_edit_distance_and_alignment() {
+    let dataset = ">Rosalind_39
+        PLEASANTLY
+        >Rosalind_11
+        MEANLY";
+    let alignment = edit_alignment_in_dataset(dataset).unwrap();
+    assert_eq!(alignment.distance, 5);
+    assert_eq!(alignment.distance, edit_distance("PLEASANTLY", "MEANLY"));
+    assert_eq!(alignment.s_aligned.replace("-", ""), "PLEASANTLY");
+    assert_eq!(alignment.t_aligned.replace("-", ""), "MEANLY");
+    assert!(hamming_distance("PLEASANTLY", "MEANLY").is_err());
+}
+
+// ALIGN ==========================================================================================
+#[test]
+fn align_should_align_translated_proteins_globally() {
+    let s = translate_rna_into_protein("AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA").unwrap();
+    let t = translate_rna_into_protein("AUGCCCAGAACUUGGGAGAUCAAUUGA").unwrap();
+    assert_eq!((&*s, &*t), ("MAMAPRTEINSTRING", "MPRTWEIN"));
+
+    let blosum62 = ScoringMatrix::blosum62();
+    let alignment = global_alignment(&s, &t, &blosum62, GapPenalty::Affine {open: 11, extend: 1}).unwrap();
+    assert_eq!(alignment.s_aligned.replace("-", ""), s);
+    assert_eq!(alignment.t_aligned.replace("-", ""), t);
+
+    let matrix: ScoringMatrix = BLOSUM62.parse().unwrap();
+    assert_eq!(matrix, blosum62);
+}
+
+#[test]
+fn align_should_find_conserved_domain_locally() {
+    let dataset = ">Rosalind_80
+        MEANLYPRTEINSTRING
+        >Rosalind_21
+        PLEASANTLYEINSTEIN";
+    let pam250 = ScoringMatrix::pam250();
+    let alignment = local_alignment_in_dataset(dataset, &pam250, GapPenalty::Linear(5)).unwrap();
+    assert_eq!(alignment.score, 23);
+    assert_eq!(alignment.s_aligned.replace("-", ""), &"MEANLYPRTEINSTRING"[alignment.s_range]);
+    assert_eq!(alignment.t_aligned.replace("-", ""), &"PLEASANTLYEINSTEIN"[alignment.t_range]);
+}
+
+#[test]
+fn align_should_place_noisy_read_on_reference() {
+    let reference = "GCAAACCATAAGCCCTACGTGCCGCCTGTTTAAACTCGCGAACTGAATCTTCTGCTTCACGGTGAAAGTACCACAATGGTATCACACCCCAAGGAAAC";
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let exact = align(reference, "CTCGCGAACTGAATC", &dna, GapPenalty::Linear(1), AlignmentMode::Fitting).unwrap();
+    assert_eq!(exact.score, 15);
+    assert_eq!(motif_lookup(reference, "CTCGCGAACTGAATC").unwrap(), vec![exact.s_range.start + 1]);
+
+    let noisy = align(reference, "CTCGCGTACTGATC", &dna, GapPenalty::Linear(1), AlignmentMode::Fitting).unwrap();
+    assert_eq!(noisy.score, 11);
+    assert_eq!(noisy.s_range, exact.s_range);
+}
+
+// MULT ===========================================================================================
+#[test]
+fn mult_should_find_global_multiple_alignment() {
+    let dataset = ">Rosalind_7
+        ATATCCG
+        >Rosalind_35
+        TCCG
+        >Rosalind_23
+        ATGTACTG
+        >Rosalind_44
+        ATGTCTG";
+    let dna = ScoringMatrix::match_mismatch(0, -1);
+    let alignment = multiple_alignment_in_dataset(dataset, &dna, 1).unwrap();
+    assert_eq!(alignment.score, -18);
+    assert_eq!(sum_of_pairs_score(&alignment.aligned, &dna, 1).unwrap(), -18);
+}
+
+#[test]
+fn mult_should_calculate_consensus_of_homologous_sequences() {
+    let homologs = vec![
+        "ATCCAGCTGATTACA",
+        "ATCCAGCTATTACA",
+        "ATCCGAGCTGATTACA",
+        "ATCAGCTGATTTACA",
+        "ATCCAGCTGATACA",
+    ];
+    assert_eq!(profile(homologs.clone()).unwrap_err(), ProfileStringsLengthError);
+
+    let dna = ScoringMatrix::match_mismatch(1, -1);
+    let alignment = progressive_multiple_alignment(&homologs, &dna, 2).unwrap();
+    let aligned: Vec<&str> = alignment.aligned.iter().map(|aligned| aligned.as_str()).collect();
+    let prof = profile(aligned).unwrap();
+    assert_eq!(prof.A.len(), alignment.aligned[0].len());
+    assert_eq!(alignment.consensus().unwrap(), "ATCCAGCTGATTACA");
+}